@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token::{self, Mint, Token, TokenAccount}};
+use crate::{constants::{SPICE_SEED, TREASURY_SEED}, errors::SpiceError, functions::{add_reward_stream as add_stream, check_admin, refund_reward_funder, refund_replaced_stream, update_reward_streams}, states::{Pool, Settings}};
+
+#[inline(never)]
+pub fn add_reward_stream(
+    ctx: Context<AddRewardStreamInstructionAccounts>,
+    slot: u8,
+    rate: u64,
+    start: i64,
+    end: i64,
+) -> Result<()> {
+    check_admin(&ctx.accounts.signer, &ctx.accounts.treasury)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    update_reward_streams(&mut ctx.accounts.pool_pda, now)?;
+
+    // Refund the stream this one replaces
+    let replaced = *ctx.accounts.pool_pda.reward_streams.get(slot as usize).ok_or(SpiceError::InvalidRewardStream)?;
+    let refund = refund_replaced_stream(&mut ctx.accounts.pool_pda, slot as usize, now)?;
+    msg!("Replaced stream refund: {}", refund);
+
+    let seeds = &[SPICE_SEED.as_bytes(), TREASURY_SEED.as_bytes(), &[ctx.bumps.treasury]];
+    let signer = [&seeds[..]];
+
+    refund_reward_funder(
+        &replaced,
+        ctx.accounts.replaced_funder_ata.clone(),
+        ctx.accounts.treasury.clone(),
+        ctx.accounts.replaced_treasury_ata.clone(),
+        refund,
+        ctx.accounts.token_program.clone(),
+        &signer)?;
+
+    let funding = add_stream(
+        &mut ctx.accounts.pool_pda,
+        slot as usize,
+        ctx.accounts.signer.key(),
+        ctx.accounts.reward_mint.key(),
+        rate,
+        (start, end),
+        now,
+    )?;
+
+    msg!("Reward stream: {}", ctx.accounts.pool_pda.reward_stream_nonce);
+    msg!("Funding: {}", funding);
+
+    // Escrow rewards in Treasury
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.signer_reward_ata.to_account_info(),
+                to: ctx.accounts.treasury_reward_ata.to_account_info(),
+                authority: ctx.accounts.signer.to_account_info(),
+            },
+        ),
+        funding,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AddRewardStreamInstructionAccounts<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub pool_pda: Account<'info, Pool>,
+
+    pub reward_mint: Account<'info, Mint>,
+
+    #[account(mut, token::authority = signer, token::mint = reward_mint)]
+    pub signer_reward_ata: Account<'info, TokenAccount>,
+
+    #[account(seeds = [SPICE_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury: Account<'info, Settings>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = reward_mint,
+        associated_token::authority = treasury,
+    )]
+    pub treasury_reward_ata: Account<'info, TokenAccount>,
+
+    // Only needed when the slot still holds an unrefunded stream
+    #[account(mut)]
+    pub replaced_funder_ata: Option<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub replaced_treasury_ata: Option<Account<'info, TokenAccount>>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
\ No newline at end of file
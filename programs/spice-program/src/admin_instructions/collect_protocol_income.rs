@@ -1,33 +1,30 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount};
+use anchor_spl::token::{Token, TokenAccount};
 
-use crate::{constants::{POOL_SEED, SOL, SPICE_SEED, TREASURY_SEED}, errors::SpiceError, functions::check_admin, states::{Pool, Settings}};
+use crate::{constants::{POOL_SEED, SPICE_SEED, TREASURY_SEED}, functions::{check_admin, take_protocol_income, transfer_from_treasury}, states::{Pool, Settings}};
 
 
-pub fn collect_protocol_income(ctx: Context<CollectProtocolIncomeInstructionAccounts>) -> Result<()> {
+pub fn collect_protocol_income(ctx: Context<CollectProtocolIncomeInstructionAccounts>, amount: Option<u64>) -> Result<()> {
     check_admin(&ctx.accounts.signer, &ctx.accounts.treasury)?;
 
-    let protocol_income = ctx.accounts.pool_pda.protocol_income;
+    let protocol_income = take_protocol_income(&mut ctx.accounts.pool_pda, amount)?;
+
+    msg!("Protocol income: {}", protocol_income);
+    msg!("Recipient: {}", ctx.accounts.recipient.key());
     
-    // Transfer from Treasury to Admin
+    // Transfer from Treasury to Recipient
     let seeds = &[SPICE_SEED.as_bytes(), TREASURY_SEED.as_bytes(), &[ctx.bumps.treasury]];
     let signer = [&seeds[..]];
 
-    if ctx.accounts.mint.key().to_string() == SOL {
-        **ctx.accounts.treasury.to_account_info().try_borrow_mut_lamports()? -= protocol_income;
-        **ctx.accounts.signer.to_account_info().try_borrow_mut_lamports()? += protocol_income;
-    } else {
-        let from_token_account = ctx.accounts.treasury_ata.as_ref().ok_or(SpiceError::MissingSPLAccount)?;
-        let to_token_account = ctx.accounts.signer_ata.as_ref().ok_or(SpiceError::MissingSPLAccount)?;
-
-        let cpi_accounts = token::Transfer {
-            from: from_token_account.to_account_info().clone(),
-            to: to_token_account.to_account_info().clone(),
-            authority: ctx.accounts.treasury.to_account_info().clone(),
-        };
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        token::transfer(CpiContext::new_with_signer(cpi_program, cpi_accounts, &signer), protocol_income)?;
-    }
+    transfer_from_treasury(
+        ctx.accounts.recipient.to_account_info(), 
+        ctx.accounts.recipient_ata.clone(), 
+        ctx.accounts.treasury.clone(), 
+        ctx.accounts.treasury_ata.clone(), 
+        ctx.accounts.mint.key(), 
+        protocol_income, 
+        ctx.accounts.token_program.clone(), 
+        &signer)?;
 
     Ok(())
 }
@@ -40,8 +37,12 @@ pub struct CollectProtocolIncomeInstructionAccounts<'info> {
     /// CHECK:
     pub mint: AccountInfo<'info>,
 
-    #[account(mut, token::authority = signer, token::mint = mint)]
-    pub signer_ata: Option<Account<'info, TokenAccount>>,
+    /// CHECK: receives the protocol income
+    #[account(mut)]
+    pub recipient: AccountInfo<'info>,
+
+    #[account(mut, token::authority = recipient, token::mint = mint)]
+    pub recipient_ata: Option<Account<'info, TokenAccount>>,
 
     #[account(mut, seeds = [POOL_SEED.as_bytes(), mint.key().as_ref()], bump)]
     pub pool_pda: Account<'info, Pool>,
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    metadata::{
+        create_metadata_accounts_v3, mpl_token_metadata::{types::DataV2, MAX_URI_LENGTH}, CreateMetadataAccountsV3, Metadata,
+        MetadataAccount,
+    },
+    token::Mint,
+};
+use crate::{constants::{MINT_SEED, POOL_SEED}, errors::SpiceError, functions::{check_admin, lp_token_name, lp_token_symbol}, states::{Pool, Settings}};
+
+#[inline(never)]
+pub fn create_lp_metadata(ctx: Context<CreateLpMetadataInstructionAccounts>, uri: String) -> Result<()> {
+    check_admin(&ctx.accounts.signer, &ctx.accounts.treasury)?;
+
+    if uri.len() > MAX_URI_LENGTH {
+        return Err(SpiceError::InvalidMetadataUri.into());
+    }
+
+    // Without underlying metadata the mint address stands in for the symbol
+    let (name, symbol) = match &ctx.accounts.mint_metadata {
+        Some(mint_metadata) => (lp_token_name(&mint_metadata.name), lp_token_symbol(&mint_metadata.symbol)),
+        None => {
+            let mint = ctx.accounts.mint.key().to_string();
+            (lp_token_name(&mint[..4]), lp_token_symbol(&mint[..4]))
+        }
+    };
+
+    msg!("Name: {}", name);
+    msg!("Symbol: {}", symbol);
+
+    let pool_pda_key = ctx.accounts.pool_pda.key();
+    let seeds = &[MINT_SEED.as_bytes(), pool_pda_key.as_ref(), &[ctx.bumps.lp_token_mint_pda]];
+    let signer = [&seeds[..]];
+
+    create_metadata_accounts_v3(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            CreateMetadataAccountsV3 {
+                metadata: ctx.accounts.lp_token_metadata.to_account_info(),
+                mint: ctx.accounts.lp_token_mint_pda.to_account_info(),
+                mint_authority: ctx.accounts.lp_token_mint_pda.to_account_info(),
+                payer: ctx.accounts.signer.to_account_info(),
+                update_authority: ctx.accounts.lp_token_mint_pda.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+            },
+            &signer,
+        ),
+        DataV2 {
+            name,
+            symbol,
+            uri,
+            seller_fee_basis_points: 0,
+            creators: None,
+            collection: None,
+            uses: None,
+        },
+        true,
+        true,
+        None,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CreateLpMetadataInstructionAccounts<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), mint.key().as_ref()],
+        seeds::program = token_metadata_program.key(),
+        bump,
+    )]
+    pub mint_metadata: Option<Account<'info, MetadataAccount>>,
+
+    #[account(seeds = [POOL_SEED.as_bytes(), mint.key().as_ref()], bump)]
+    pub pool_pda: Account<'info, Pool>,
+
+    #[account(seeds = [MINT_SEED.as_bytes(), pool_pda.key().as_ref()], bump)]
+    pub lp_token_mint_pda: Account<'info, Mint>,
+
+    /// CHECK: created by the token metadata program
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), lp_token_mint_pda.key().as_ref()],
+        seeds::program = token_metadata_program.key(),
+        bump,
+    )]
+    pub lp_token_metadata: UncheckedAccount<'info>,
+
+    pub treasury: Account<'info, Settings>,
+
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
\ No newline at end of file
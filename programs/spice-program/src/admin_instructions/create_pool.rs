@@ -1,21 +1,24 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{associated_token::AssociatedToken, token::{Mint, Token, TokenAccount}};
 use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
-use crate::{constants::{MINT_SEED, POOL_SEED, SPICE_SEED, TREASURY_SEED}, functions::check_admin, states::{Pool, Settings}};
+use crate::{constants::{MINT_SEED, POOL_SEED, POOL_SPACE, SPICE_SEED, TREASURY_SEED}, functions::{check_admin, check_fee_params, check_withdrawal_fee_params, new_pool}, states::{Pool, Settings}};
 
 #[inline(never)]
 pub fn create_pool(ctx: Context<InitPoolInstructionAccounts>, is_active: bool, base_fee: u64) -> Result<()> {
     check_admin(&ctx.accounts.signer, &ctx.accounts.treasury)?;
 
-    ctx.accounts.pool_pda.is_active = is_active;
-    ctx.accounts.pool_pda.base_fee = base_fee;
-    ctx.accounts.pool_pda.mint = ctx.accounts.mint.key();
-    ctx.accounts.pool_pda.pyth_price_feed_account = ctx.accounts.pyth_price_feed_account.key();
-    ctx.accounts.pool_pda.lp_token_mint = ctx.accounts.lp_token_mint_pda.key();
-    ctx.accounts.pool_pda.initial_liquidity = 0;
-    ctx.accounts.pool_pda.cumulative_yield = 0;
-    ctx.accounts.pool_pda.current_liquidity = 0;
-    ctx.accounts.pool_pda.protocol_income = 0;
+    let pool = new_pool(
+        is_active,
+        base_fee,
+        ctx.accounts.mint.key(),
+        ctx.accounts.pyth_price_feed_account.key(),
+        ctx.accounts.lp_token_mint_pda.key(),
+    );
+    ctx.accounts.pool_pda.set_inner(pool);
+
+    check_fee_params(base_fee, &ctx.accounts.pool_pda.imbalance_fee)?;
+    check_fee_params(base_fee, &ctx.accounts.pool_pda.regular_fee)?;
+    check_withdrawal_fee_params(&ctx.accounts.pool_pda.withdrawal_fee)?;
 
     Ok(())
 }
@@ -34,7 +37,7 @@ pub struct InitPoolInstructionAccounts<'info> {
         payer = signer,
         seeds = [POOL_SEED.as_bytes(), mint.key().as_ref()],
         bump,
-        space = 8 + 1 + 8 + 32 + 32 + 32 + 8 + 8 + 8 + 8,
+        space = POOL_SPACE,
     )]
     pub pool_pda: Account<'info, Pool>,
 
@@ -0,0 +1,76 @@
+use anchor_lang::{prelude::*, system_program, Discriminator};
+use anchor_spl::token::Mint;
+use crate::{constants::{LEGACY_POOL_SPACE, MINT_SEED, POOL_SEED, POOL_SPACE}, errors::SpiceError, functions::{check_admin, migrate_legacy_pool}, states::{LegacyPool, Pool, Settings}};
+
+// Rewrites a Pool created with the liquidity counters only layout.
+// collected_income is the protocol income already taken out with the legacy collect_protocol_income
+#[inline(never)]
+pub fn migrate_pool(ctx: Context<MigratePoolInstructionAccounts>, collected_income: u64) -> Result<()> {
+    check_admin(&ctx.accounts.signer, &ctx.accounts.treasury)?;
+
+    let pool_info = ctx.accounts.pool_pda.to_account_info();
+
+    if pool_info.owner != &crate::ID {
+        return Err(ErrorCode::AccountOwnedByWrongProgram.into());
+    }
+    if pool_info.data_len() != LEGACY_POOL_SPACE {
+        return Err(SpiceError::PoolAlreadyMigrated.into());
+    }
+
+    let legacy = {
+        let data = pool_info.try_borrow_data()?;
+        if data[..8] != Pool::DISCRIMINATOR {
+            return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+        }
+        LegacyPool::deserialize(&mut &data[8..])?
+    };
+
+    if legacy.lp_token_mint != ctx.accounts.lp_token_mint_pda.key() {
+        return Err(ErrorCode::ConstraintTokenMint.into());
+    }
+
+    let pool = Box::new(migrate_legacy_pool(&legacy, ctx.accounts.lp_token_mint_pda.supply, collected_income)?);
+
+    msg!("LP supply: {}", pool.lp_supply);
+    msg!("Legacy protocol income: {}", legacy.protocol_income);
+    msg!("Protocol income: {}", pool.protocol_income);
+
+    // Top up rent for the larger account
+    let rent = Rent::get()?.minimum_balance(POOL_SPACE);
+    if rent > pool_info.lamports() {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.signer.to_account_info(),
+                    to: pool_info.clone(),
+                },
+            ),
+            rent - pool_info.lamports(),
+        )?;
+    }
+
+    pool_info.realloc(POOL_SPACE, false)?;
+    pool.try_serialize(&mut &mut pool_info.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigratePoolInstructionAccounts<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// CHECK:
+    pub mint: AccountInfo<'info>,
+
+    /// CHECK: deserialized by hand, the legacy layout does not match Pool
+    #[account(mut, seeds = [POOL_SEED.as_bytes(), mint.key().as_ref()], bump)]
+    pub pool_pda: UncheckedAccount<'info>,
+
+    #[account(seeds = [MINT_SEED.as_bytes(), pool_pda.key().as_ref()], bump)]
+    pub lp_token_mint_pda: Account<'info, Mint>,
+
+    pub treasury: Account<'info, Settings>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,69 @@
+use anchor_lang::{prelude::*, system_program, Discriminator};
+use crate::{constants::{LEGACY_SETTINGS_SPACE, SETTINGS_SPACE, SETTINGS_VERSION, SPICE_SEED, TREASURY_SEED}, errors::SpiceError, functions::protocol_share_from_divisor, states::{FeeSplit, LegacySettings, Settings}};
+
+// Rewrites Settings created with the income divisor layout
+pub fn migrate_settings(ctx: Context<MigrateSettingsInstructionAccounts>) -> Result<()> {
+    let treasury = ctx.accounts.treasury.to_account_info();
+
+    if treasury.owner != &crate::ID {
+        return Err(ErrorCode::AccountOwnedByWrongProgram.into());
+    }
+    if treasury.data_len() != LEGACY_SETTINGS_SPACE {
+        return Err(SpiceError::SettingsAlreadyMigrated.into());
+    }
+
+    let legacy = {
+        let data = treasury.try_borrow_data()?;
+        if data[..8] != Settings::DISCRIMINATOR {
+            return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+        }
+        LegacySettings::deserialize(&mut &data[8..])?
+    };
+
+    if ctx.accounts.signer.key() != legacy.admin {
+        return Err(SpiceError::InvalidAdmin.into());
+    }
+
+    let settings = Settings {
+        admin: legacy.admin,
+        protocol_share: protocol_share_from_divisor(legacy.income_distribution),
+        stoptap: legacy.stoptap,
+        version: SETTINGS_VERSION,
+        fee_split: FeeSplit::default(),
+    };
+
+    msg!("Income distribution: {}", legacy.income_distribution);
+    msg!("Protocol share: {}", settings.protocol_share);
+
+    // Top up rent for the larger account
+    let rent = Rent::get()?.minimum_balance(SETTINGS_SPACE);
+    if rent > treasury.lamports() {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.signer.to_account_info(),
+                    to: treasury.clone(),
+                },
+            ),
+            rent - treasury.lamports(),
+        )?;
+    }
+
+    treasury.realloc(SETTINGS_SPACE, false)?;
+    settings.try_serialize(&mut &mut treasury.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateSettingsInstructionAccounts<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// CHECK: deserialized by hand, the legacy layout does not match Settings
+    #[account(mut, seeds = [SPICE_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -3,9 +3,31 @@ pub use update_settings::*;
 pub use create_pool::*;
 pub use set_pool_settings::*;
 pub use collect_protocol_income::*;
+pub use set_fee_curve::*;
+pub use set_rebate_settings::*;
+pub use add_reward_stream::*;
+pub use create_lp_metadata::*;
+pub use update_lp_metadata_uri::*;
+pub use set_deficit_coverage::*;
+pub use set_fee_split::*;
+pub use set_pool_protocol_share::*;
+pub use migrate_settings::*;
+pub use set_withdrawal_fee::*;
+pub use migrate_pool::*;
 
 pub mod treasury_settings;
 pub mod update_settings;
 pub mod create_pool;
 pub mod set_pool_settings;
-pub mod collect_protocol_income;
\ No newline at end of file
+pub mod collect_protocol_income;
+pub mod set_fee_curve;
+pub mod set_rebate_settings;
+pub mod add_reward_stream;
+pub mod create_lp_metadata;
+pub mod update_lp_metadata_uri;
+pub mod set_deficit_coverage;
+pub mod set_fee_split;
+pub mod set_pool_protocol_share;
+pub mod migrate_settings;
+pub mod set_withdrawal_fee;
+pub mod migrate_pool;
\ No newline at end of file
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use crate::{constants::FEE_SCALE, errors::SpiceError, functions::{check_admin, cover_deficit}, states::{Pool, Settings}};
+
+#[inline(never)]
+pub fn set_deficit_coverage(ctx: Context<SetDeficitCoverageInstructionAccounts>, deficit_coverage: u64) -> Result<()> {
+    check_admin(&ctx.accounts.signer, &ctx.accounts.treasury)?;
+
+    if deficit_coverage as u128 > FEE_SCALE {
+        return Err(SpiceError::InvalidFee.into());
+    }
+
+    msg!("Deficit coverage: {}", deficit_coverage);
+    ctx.accounts.pool_pda.deficit_coverage = deficit_coverage;
+
+    let covered = cover_deficit(&mut ctx.accounts.pool_pda)?;
+    msg!("Deficit covered: {}", covered);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetDeficitCoverageInstructionAccounts<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub pool_pda: Account<'info, Pool>,
+
+    pub treasury: Account<'info, Settings>,
+    pub system_program: Program<'info, System>,
+}
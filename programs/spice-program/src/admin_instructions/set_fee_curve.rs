@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+use crate::{functions::{check_admin, check_fee_curve}, states::{FeeCurve, Pool, Settings}};
+
+#[inline(never)]
+pub fn set_fee_curve(ctx: Context<SetFeeCurveInstructionAccounts>, fee_curve: FeeCurve) -> Result<()> {
+    check_admin(&ctx.accounts.signer, &ctx.accounts.treasury)?;
+    check_fee_curve(&fee_curve)?;
+
+    ctx.accounts.pool_pda.fee_curve = fee_curve;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetFeeCurveInstructionAccounts<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub pool_pda: Account<'info, Pool>,
+
+    pub treasury: Account<'info, Settings>,
+    pub system_program: Program<'info, System>,
+}
\ No newline at end of file
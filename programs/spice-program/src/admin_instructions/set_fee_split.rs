@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+use crate::{constants::{MAX_FEE_RECIPIENTS, SPICE_SEED, TREASURY_SEED}, functions::{check_admin, check_fee_split}, states::{FeeRecipient, Settings}};
+
+// New weights only apply to protocol income accrued from now on
+#[inline(never)]
+pub fn set_fee_split(ctx: Context<SetFeeSplitInstructionAccounts>, recipients: Vec<FeeRecipient>) -> Result<()> {
+    check_admin(&ctx.accounts.signer, &ctx.accounts.treasury)?;
+    check_fee_split(&recipients)?;
+
+    let fee_split = &mut ctx.accounts.treasury.fee_split;
+    fee_split.recipients = [FeeRecipient::default(); MAX_FEE_RECIPIENTS];
+    fee_split.recipients[..recipients.len()].copy_from_slice(&recipients);
+    fee_split.count = recipients.len() as u8;
+
+    for recipient in &recipients {
+        msg!("Fee recipient: {} weight: {}", recipient.address, recipient.weight);
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetFeeSplitInstructionAccounts<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(mut, seeds = [SPICE_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury: Account<'info, Settings>,
+}
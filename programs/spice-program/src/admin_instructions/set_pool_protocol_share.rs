@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+use crate::{functions::{check_admin, check_protocol_share}, states::{Pool, Settings}};
+
+#[inline(never)]
+pub fn set_pool_protocol_share(ctx: Context<SetPoolProtocolShareInstructionAccounts>, protocol_share: Option<u64>) -> Result<()> {
+    check_admin(&ctx.accounts.signer, &ctx.accounts.treasury)?;
+
+    if let Some(protocol_share) = protocol_share {
+        check_protocol_share(protocol_share)?;
+        msg!("Protocol share: {}", protocol_share);
+    }
+
+    ctx.accounts.pool_pda.protocol_share = protocol_share;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetPoolProtocolShareInstructionAccounts<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub pool_pda: Account<'info, Pool>,
+
+    pub treasury: Account<'info, Settings>,
+    pub system_program: Program<'info, System>,
+}
@@ -1,15 +1,34 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::Token;
-use crate::{functions::check_admin, states::{Pool, Settings}};
+use crate::{constants::FEE_SCALE, errors::SpiceError, functions::{check_admin, check_fee_params}, states::{DynamicFeeParams, Pool, Settings}};
 
 #[inline(never)]
-pub fn set_pool_settings(ctx: Context<SetPoolSettingsInstructionAccounts>, is_active: bool, base_fee: u64) -> Result<()> {
+pub fn set_pool_settings(
+    ctx: Context<SetPoolSettingsInstructionAccounts>,
+    is_active: bool,
+    base_fee: u64,
+    flash_loan_fee: u64,
+    imbalance_fee: DynamicFeeParams,
+    regular_fee: DynamicFeeParams,
+) -> Result<()> {
     check_admin(&ctx.accounts.signer, &ctx.accounts.treasury)?;
+    check_fee_params(base_fee, &imbalance_fee)?;
+    check_fee_params(base_fee, &regular_fee)?;
     
+    if flash_loan_fee as u128 > FEE_SCALE {
+        return Err(SpiceError::InvalidFee.into());
+    }
+
     msg!("Base fee: {}", base_fee);
+    msg!("Flash loan fee: {}", flash_loan_fee);
+    msg!("Imbalance fee: {} / {}", imbalance_fee.max_fee, imbalance_fee.steepness);
+    msg!("Regular fee: {} / {}", regular_fee.max_fee, regular_fee.steepness);
     msg!("State: {}", is_active);
     ctx.accounts.pool_pda.is_active = is_active;
     ctx.accounts.pool_pda.base_fee = base_fee;
+    ctx.accounts.pool_pda.flash_loan_fee = flash_loan_fee;
+    ctx.accounts.pool_pda.imbalance_fee = imbalance_fee;
+    ctx.accounts.pool_pda.regular_fee = regular_fee;
 
     Ok(())
 }
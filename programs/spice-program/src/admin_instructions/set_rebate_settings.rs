@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::{constants::FEE_SCALE, errors::SpiceError, functions::check_admin, states::{Pool, Settings}};
+
+#[inline(never)]
+pub fn set_rebate_settings(
+    ctx: Context<SetRebateSettingsInstructionAccounts>,
+    rebate_rate: u64,
+    max_rebate_per_trade: u64,
+    max_rebate_per_epoch: u64,
+) -> Result<()> {
+    check_admin(&ctx.accounts.signer, &ctx.accounts.treasury)?;
+
+    if rebate_rate as u128 > FEE_SCALE || max_rebate_per_trade > max_rebate_per_epoch {
+        return Err(SpiceError::InvalidFee.into());
+    }
+
+    msg!("Rebate rate: {}", rebate_rate);
+    msg!("Max rebate per trade: {}", max_rebate_per_trade);
+    msg!("Max rebate per epoch: {}", max_rebate_per_epoch);
+    ctx.accounts.pool_pda.rebate_rate = rebate_rate;
+    ctx.accounts.pool_pda.max_rebate_per_trade = max_rebate_per_trade;
+    ctx.accounts.pool_pda.max_rebate_per_epoch = max_rebate_per_epoch;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetRebateSettingsInstructionAccounts<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub pool_pda: Account<'info, Pool>,
+
+    pub treasury: Account<'info, Settings>,
+    pub system_program: Program<'info, System>,
+}
\ No newline at end of file
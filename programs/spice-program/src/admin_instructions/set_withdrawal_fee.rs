@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+use crate::{functions::{check_admin, check_withdrawal_fee_params}, states::{Pool, Settings, WithdrawalFeeParams}};
+
+#[inline(never)]
+pub fn set_withdrawal_fee(ctx: Context<SetWithdrawalFeeInstructionAccounts>, withdrawal_fee: WithdrawalFeeParams) -> Result<()> {
+    check_admin(&ctx.accounts.signer, &ctx.accounts.treasury)?;
+    check_withdrawal_fee_params(&withdrawal_fee)?;
+
+    msg!("Withdrawal fee: {} / {} / {}", withdrawal_fee.max_fee, withdrawal_fee.depth_steepness, withdrawal_fee.size_steepness);
+    ctx.accounts.pool_pda.withdrawal_fee = withdrawal_fee;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetWithdrawalFeeInstructionAccounts<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(mut)]
+    pub pool_pda: Account<'info, Pool>,
+
+    pub treasury: Account<'info, Settings>,
+    pub system_program: Program<'info, System>,
+}
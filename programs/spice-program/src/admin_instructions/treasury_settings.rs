@@ -1,15 +1,17 @@
 use anchor_lang::prelude::*;
-use crate::{constants::{ADMINISTRATOR, SPICE_SEED, TREASURY_SEED}, errors::SpiceError, states::Settings};
+use crate::{constants::{ADMINISTRATOR, SETTINGS_SPACE, SETTINGS_VERSION, SPICE_SEED, TREASURY_SEED}, errors::SpiceError, functions::check_protocol_share, states::Settings};
 
 
-pub fn treasury_settings(ctx: Context<TreasurySettingsInstructionAccounts>, income_distribution: u64, stoptap: bool) -> Result<()> {
+pub fn treasury_settings(ctx: Context<TreasurySettingsInstructionAccounts>, protocol_share: u64, stoptap: bool) -> Result<()> {
     if ctx.accounts.signer.key.to_string() != ADMINISTRATOR {
         return Err(SpiceError::InvalidAdmin.into());
     }
+    check_protocol_share(protocol_share)?;
 
     ctx.accounts.treasury.admin = ctx.accounts.signer.key();
-    ctx.accounts.treasury.income_distribution = income_distribution;
+    ctx.accounts.treasury.protocol_share = protocol_share;
     ctx.accounts.treasury.stoptap = stoptap;
+    ctx.accounts.treasury.version = SETTINGS_VERSION;
 
     Ok(())
 }
@@ -24,7 +26,7 @@ pub struct TreasurySettingsInstructionAccounts<'info> {
         payer = signer,
         seeds = [SPICE_SEED.as_bytes(), TREASURY_SEED.as_bytes()],
         bump,
-        space = 8 + 32 + 8 + 1,
+        space = SETTINGS_SPACE,
     )]
     pub treasury: Account<'info, Settings>,
     
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    metadata::{
+        mpl_token_metadata::{types::DataV2, MAX_URI_LENGTH}, update_metadata_accounts_v2, Metadata, MetadataAccount,
+        UpdateMetadataAccountsV2,
+    },
+    token::Mint,
+};
+use crate::{constants::{MINT_SEED, POOL_SEED}, errors::SpiceError, functions::check_admin, states::{Pool, Settings}};
+
+#[inline(never)]
+pub fn update_lp_metadata_uri(ctx: Context<UpdateLpMetadataUriInstructionAccounts>, uri: String) -> Result<()> {
+    check_admin(&ctx.accounts.signer, &ctx.accounts.treasury)?;
+
+    if uri.len() > MAX_URI_LENGTH {
+        return Err(SpiceError::InvalidMetadataUri.into());
+    }
+
+    msg!("URI: {}", uri);
+
+    let metadata = &ctx.accounts.lp_token_metadata;
+    let data = DataV2 {
+        name: metadata.name.clone(),
+        symbol: metadata.symbol.clone(),
+        uri,
+        seller_fee_basis_points: metadata.seller_fee_basis_points,
+        creators: metadata.creators.clone(),
+        collection: metadata.collection.clone(),
+        uses: metadata.uses.clone(),
+    };
+
+    let pool_pda_key = ctx.accounts.pool_pda.key();
+    let seeds = &[MINT_SEED.as_bytes(), pool_pda_key.as_ref(), &[ctx.bumps.lp_token_mint_pda]];
+    let signer = [&seeds[..]];
+
+    update_metadata_accounts_v2(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            UpdateMetadataAccountsV2 {
+                metadata: ctx.accounts.lp_token_metadata.to_account_info(),
+                update_authority: ctx.accounts.lp_token_mint_pda.to_account_info(),
+            },
+            &signer,
+        ),
+        None,
+        Some(data),
+        None,
+        None,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateLpMetadataUriInstructionAccounts<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// CHECK:
+    pub mint: AccountInfo<'info>,
+
+    #[account(seeds = [POOL_SEED.as_bytes(), mint.key().as_ref()], bump)]
+    pub pool_pda: Account<'info, Pool>,
+
+    #[account(seeds = [MINT_SEED.as_bytes(), pool_pda.key().as_ref()], bump)]
+    pub lp_token_mint_pda: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), lp_token_mint_pda.key().as_ref()],
+        seeds::program = token_metadata_program.key(),
+        bump,
+    )]
+    pub lp_token_metadata: Account<'info, MetadataAccount>,
+
+    pub treasury: Account<'info, Settings>,
+
+    pub token_metadata_program: Program<'info, Metadata>,
+}
\ No newline at end of file
@@ -1,12 +1,13 @@
 use anchor_lang::prelude::*;
-use crate::{constants::{SPICE_SEED, TREASURY_SEED}, functions::check_admin, states::Settings};
+use crate::{constants::{SPICE_SEED, TREASURY_SEED}, functions::{check_admin, check_protocol_share}, states::Settings};
 
 
-pub fn update_settings(ctx: Context<UpdateSettingsInstructionAccounts>, income_distribution: u64, stoptap: bool) -> Result<()> {
+pub fn update_settings(ctx: Context<UpdateSettingsInstructionAccounts>, protocol_share: u64, stoptap: bool) -> Result<()> {
     check_admin(&ctx.accounts.signer, &ctx.accounts.treasury)?;
+    check_protocol_share(protocol_share)?;
 
     ctx.accounts.treasury.admin = ctx.accounts.new_admin.key();
-    ctx.accounts.treasury.income_distribution = income_distribution;
+    ctx.accounts.treasury.protocol_share = protocol_share;
     ctx.accounts.treasury.stoptap = stoptap;
 
     Ok(())
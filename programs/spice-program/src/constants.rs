@@ -3,10 +3,43 @@ pub const TREASURY_SEED: &str = "TREASURY";
 pub const POOL_SEED: &str = "POOL";
 pub const MINT_SEED: &str = "MINT";
 pub const PROVIDER_SEED: &str = "PROVIDER";
+pub const DCA_SEED: &str = "DCA";
+pub const POSITION_SEED: &str = "POSITION";
+pub const POSITION_REGISTRY_SEED: &str = "POSITIONS";
 
 pub const SOL: &str = "So11111111111111111111111111111111111111112";
 
 pub const ADMINISTRATOR: &str = "aZZ8CAZ1b1Ar3x4UoB6QxTeobpg5DusHYDM1NpLX8mQ";
 
 pub  const SPICE_SCALE: u64 = 1_000;
-pub  const FEE_SCALE: u128 = 100_000;
\ No newline at end of file
+pub  const FEE_SCALE: u128 = 100_000;
+pub const FIXED_POINT_SCALE: u128 = 1_000_000_000_000;
+pub const BPS_SCALE: u128 = 10_000;
+
+pub const MAX_DYNAMIC_FEE: u64 = 50_000;
+pub const MAX_FEE_STEEPNESS: u64 = 100 * SPICE_SCALE;
+pub const MAX_FEE_BREAKPOINTS: usize = 8;
+pub const MAX_REWARD_STREAMS: usize = 3;
+pub const REWARD_CLAIM_WINDOW: i64 = 30 * 86_400;
+pub const MAX_REWARD_DURATION: i64 = 365 * 86_400;
+pub const ADMIN_REWARD_SLOTS: usize = 1;
+pub const MIN_REWARD_RATE: u64 = 1_000;
+pub const MAX_POSITIONS: u8 = 64;
+pub const MINIMUM_LP_SUPPLY: u64 = 1_000;
+pub const MAX_KEEPER_TIP: u64 = 500; // scale 1:BPS_SCALE
+pub const MAX_FEE_RECIPIENTS: usize = 4;
+pub const MAX_FEE_SHARES: usize = 2 * MAX_FEE_RECIPIENTS;
+pub const SETTINGS_VERSION: u8 = 1;
+pub const LEGACY_SETTINGS_SPACE: usize = 8 + 32 + 8 + 1;
+pub const SETTINGS_SPACE: usize = LEGACY_SETTINGS_SPACE + 1 + MAX_FEE_RECIPIENTS * 40 + 1;
+pub const LEGACY_PROVIDER_SPACE: usize = 8 + 32 + 8 + 8 + 8;
+pub const POOL_VERSION: u8 = 1;
+pub const LEGACY_POOL_SPACE: usize = 8 + 1 + 8 + 32 + 32 + 32 + 8 + 8 + 8 + 8;
+pub const POOL_SPACE: usize = LEGACY_POOL_SPACE + 8 + 8 + 16 + 16 + 1 + MAX_FEE_BREAKPOINTS * 16 + 1 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + MAX_REWARD_STREAMS * 136 + 8 + 8 + MAX_FEE_SHARES * 40 + 1 + 8 + 24 + 1;
+
+// (duration in seconds, yield boost scale 1:SPICE_SCALE)
+pub const LOCK_TERMS: [(i64, u64); 3] = [
+    (30 * 86_400, 1_100),
+    (90 * 86_400, 1_250),
+    (180 * 86_400, 1_500),
+];
\ No newline at end of file
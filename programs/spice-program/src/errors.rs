@@ -58,5 +58,101 @@ pub enum SpiceError {
     PriceNotAvailable,
 
     #[msg("High volatility")]
-    HighVolatility
+    HighVolatility,
+
+    #[msg("Invalid number of DCA slices")]
+    InvalidSliceCount,
+
+    #[msg("DCA interval not elapsed")]
+    DcaIntervalNotElapsed,
+
+    #[msg("DCA order completed")]
+    DcaOrderCompleted,
+
+    #[msg("Invalid fee")]
+    InvalidFee,
+
+    #[msg("Pool not active")]
+    PoolNotActive,
+
+    #[msg("Flash loan already active")]
+    FlashLoanActive,
+
+    #[msg("No active flash loan")]
+    NoFlashLoan,
+
+    #[msg("Flash loan not repaid in the same transaction")]
+    FlashLoanNotRepaid,
+
+    #[msg("Flash loan must be a top-level instruction")]
+    FlashLoanCpi,
+
+    #[msg("Invalid fee curve")]
+    InvalidFeeCurve,
+
+    #[msg("Keeper not enabled for this position")]
+    KeeperNotEnabled,
+
+    #[msg("Invalid keeper tip")]
+    InvalidKeeperTip,
+
+    #[msg("Invalid lock term")]
+    InvalidLockTerm,
+
+    #[msg("Liquidity is locked")]
+    LiquidityLocked,
+
+    #[msg("Lock not expired")]
+    LockNotExpired,
+
+    #[msg("Invalid reward stream")]
+    InvalidRewardStream,
+
+    #[msg("No free reward stream slot")]
+    NoFreeRewardSlot,
+
+    #[msg("Reward stream not ended")]
+    RewardStreamNotEnded,
+
+    #[msg("Invalid metadata URI")]
+    InvalidMetadataUri,
+
+    #[msg("Provider still holds liquidity or unclaimed income")]
+    ProviderNotEmpty,
+
+    #[msg("Invalid position index")]
+    InvalidPositionIndex,
+
+    #[msg("Pool is below its target liquidity")]
+    PoolInDeficit,
+
+    #[msg("Invalid protocol income amount")]
+    InvalidProtocolIncomeAmount,
+
+    #[msg("Invalid fee split")]
+    InvalidFeeSplit,
+
+    #[msg("No free fee share slot")]
+    NoFreeFeeShareSlot,
+
+    #[msg("No protocol fees to claim")]
+    NoFeeShare,
+
+    #[msg("Invalid protocol share")]
+    InvalidProtocolShare,
+
+    #[msg("Settings already migrated")]
+    SettingsAlreadyMigrated,
+
+    #[msg("Input and output mints must differ")]
+    SameMint,
+
+    #[msg("Pool already migrated")]
+    PoolAlreadyMigrated,
+
+    #[msg("Reward stream not refunded")]
+    RewardStreamNotRefunded,
+
+    #[msg("Merge would extend the destination lock")]
+    LockExtended
 }
\ No newline at end of file
@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::{errors::SpiceError, states::Settings};
+use crate::{constants::{FEE_SCALE, MAX_DYNAMIC_FEE, MAX_FEE_BREAKPOINTS, MAX_FEE_STEEPNESS}, errors::SpiceError, states::{DynamicFeeParams, FeeCurve, Pool, Settings, WithdrawalFeeParams}};
 
 pub fn check_stoptap(settings: &Account<Settings>) -> Result<()> {
     if settings.stoptap {
@@ -13,5 +13,78 @@ pub fn check_admin(signer: &Signer, settings: &Account<Settings>) -> Result<()>
         return Err(SpiceError::InvalidAdmin.into());
     }
     
+    Ok(())
+}
+
+pub fn check_pools(pool_a: &Pool, pool_b: &Pool, pyth_a: Pubkey, pyth_b: Pubkey) -> Result<()> {
+    if !pool_a.is_active {
+        return Err(SpiceError::PoolANotActive.into());
+    }
+    if !pool_b.is_active {
+        return Err(SpiceError::PoolBNotActive.into());
+    }
+    if pyth_a != pool_a.pyth_price_feed_account {
+        return Err(SpiceError::InvalidPythAccount.into());
+    }
+    if pyth_b != pool_b.pyth_price_feed_account {
+        return Err(SpiceError::InvalidPythAccount.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_withdrawal_fee_params(params: &WithdrawalFeeParams) -> Result<()> {
+    if params.max_fee > MAX_DYNAMIC_FEE
+        || params.depth_steepness > MAX_FEE_STEEPNESS
+        || params.size_steepness > MAX_FEE_STEEPNESS
+    {
+        return Err(SpiceError::InvalidFee.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_protocol_share(protocol_share: u64) -> Result<()> {
+    if u128::from(protocol_share) > FEE_SCALE {
+        return Err(SpiceError::InvalidProtocolShare.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_fee_params(base_fee: u64, params: &DynamicFeeParams) -> Result<()> {
+    if params.max_fee < base_fee || params.max_fee > MAX_DYNAMIC_FEE {
+        return Err(SpiceError::InvalidFee.into());
+    }
+    if params.steepness == 0 || params.steepness > MAX_FEE_STEEPNESS {
+        return Err(SpiceError::InvalidFee.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_fee_curve(curve: &FeeCurve) -> Result<()> {
+    if let FeeCurve::PiecewiseLinear { breakpoints, count } = curve {
+        let count = *count as usize;
+        if count == 0 || count > MAX_FEE_BREAKPOINTS {
+            return Err(SpiceError::InvalidFeeCurve.into());
+        }
+
+        let mut previous = None;
+        for breakpoint in &breakpoints[..count] {
+            if breakpoint.fee as u128 > FEE_SCALE {
+                return Err(SpiceError::InvalidFeeCurve.into());
+            }
+            if let Some((usage, fee)) = previous {
+                if breakpoint.usage <= usage || breakpoint.fee < fee {
+                    return Err(SpiceError::InvalidFeeCurve.into());
+                }
+            } else if breakpoint.usage == 0 {
+                return Err(SpiceError::InvalidFeeCurve.into());
+            }
+            previous = Some((breakpoint.usage, breakpoint.fee));
+        }
+    }
+
     Ok(())
 }
\ No newline at end of file
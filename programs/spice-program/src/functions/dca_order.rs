@@ -0,0 +1,48 @@
+use crate::{errors::SpiceError, states::DcaOrder};
+
+// Returns the amount of every slice but the last one
+pub fn check_dca_order(amount: u64, slices: u64, min_interval: i64) -> Result<u64, SpiceError> {
+    if slices == 0 || amount < slices || min_interval < 0 {
+        return Err(SpiceError::InvalidSliceCount);
+    }
+
+    Ok(amount / slices)
+}
+
+// Input of the next slice, the last slice also takes the rounding remainder of the deposit
+pub fn next_dca_slice(order: &DcaOrder, now: i64) -> Result<u64, SpiceError> {
+    if order.executed_slices >= order.slices {
+        return Err(SpiceError::DcaOrderCompleted);
+    }
+    if order.executed_slices > 0 && now < order.last_execution.saturating_add(order.min_interval) {
+        return Err(SpiceError::DcaIntervalNotElapsed);
+    }
+
+    if order.executed_slices + 1 == order.slices {
+        Ok(order.remaining_amount)
+    } else {
+        Ok(order.amount_per_slice)
+    }
+}
+
+pub fn record_dca_slice(order: &mut DcaOrder, amount_in: u64, amount_out: u64, now: i64) -> Result<(), SpiceError> {
+    if amount_out < order.min_amount_out {
+        return Err(SpiceError::HighSlippage);
+    }
+
+    order.remaining_amount = order.remaining_amount
+        .checked_sub(amount_in)
+        .ok_or(SpiceError::Overflow)?;
+    order.executed_slices += 1;
+    order.last_execution = now;
+
+    Ok(())
+}
+
+// Whatever was not swapped yet goes back to the owner
+pub fn cancel_dca_order(order: &mut DcaOrder) -> u64 {
+    let refund = order.remaining_amount;
+    order.remaining_amount = 0;
+
+    refund
+}
\ No newline at end of file
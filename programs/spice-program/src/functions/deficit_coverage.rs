@@ -0,0 +1,23 @@
+use crate::{constants::FEE_SCALE, errors::SpiceError, functions::pool_value, states::Pool};
+
+// Moves the configured share of protocol income back into a pool below its target.
+// Runs after every swap, flash loan repayment and coverage change, and before every deposit
+// and withdrawal. Liquidity lent out by an outstanding flash loan is not a deficit
+pub fn cover_deficit(pool: &mut Pool) -> Result<u64, SpiceError> {
+    let deficit = pool.initial_liquidity.saturating_sub(pool_value(pool)?);
+    if deficit == 0 {
+        return Ok(0);
+    }
+
+    let available = u128::from(pool.protocol_income)
+        .checked_mul(u128::from(pool.deficit_coverage))
+        .and_then(|num| num.checked_div(FEE_SCALE))
+        .ok_or(SpiceError::Overflow)? as u64;
+
+    let covered = available.min(deficit);
+
+    pool.protocol_income -= covered;
+    pool.current_liquidity += covered;
+
+    Ok(covered)
+}
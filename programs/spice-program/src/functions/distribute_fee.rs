@@ -0,0 +1,36 @@
+use crate::{constants::{FEE_SCALE, SPICE_SCALE}, errors::SpiceError, functions::{pool_value, split_protocol_fee}, states::{Pool, Settings}};
+
+// The pool's own protocol share, when set, overrides the global one
+pub fn distribute_fee(
+    pool: &mut Pool,
+    fee_amount: u64,
+    settings: &Settings,
+) -> Result<(), SpiceError> {
+    let protocol_share = pool.protocol_share.unwrap_or(settings.protocol_share);
+    if u128::from(protocol_share) > FEE_SCALE {
+        return Err(SpiceError::InvalidProtocolShare);
+    }
+
+    // Protocol income is unscaled, rounding dust goes to providers
+    let protocol_income = (u128::from(fee_amount) * u128::from(protocol_share) / FEE_SCALE) as u64;
+    let provider_income = (fee_amount - protocol_income)
+        .checked_mul(SPICE_SCALE)
+        .ok_or(SpiceError::Overflow)?;
+
+    // Recipients are paid only while the pool is at its target, otherwise the income backs it
+    let split = if pool_value(pool)? >= pool.initial_liquidity {
+        split_protocol_fee(pool, &settings.fee_split, protocol_income)?
+    } else {
+        0
+    };
+
+    pool.protocol_income = pool.protocol_income
+        .checked_add(protocol_income - split)
+        .ok_or(SpiceError::Overflow)?;
+
+    pool.cumulative_yield = pool.cumulative_yield
+        .checked_add(provider_income)
+        .ok_or(SpiceError::Overflow)?;
+
+    Ok(())
+}
@@ -1,37 +1,55 @@
 use solana_program::msg;
 
-use crate::errors::SpiceError;
+use crate::{constants::FEE_SCALE, errors::SpiceError, functions::{curve_fee, pool_value}, states::Pool};
 
 pub fn dynamic_fee(
-    fee_out: u64,
-    amount_in: u64,
+    pool_a: &Pool,
+    pool_b: &Pool,
     amount_out: u64,
-    pool_a_initial_liquidity: u64,
-    pool_a_current_liquidity: u64,
-    pool_b_initial_liquidity: u64,
-    pool_b_current_liquidity: u64,
 ) -> Result<u64, SpiceError> {
-    if pool_b_current_liquidity == 0 {
+    if pool_b.current_liquidity == 0 {
         return Err(SpiceError::NoLiquidity);
     }
 
-    let pool_a_delta = pool_a_current_liquidity as i64 - pool_a_initial_liquidity as i64;
-    let pool_b_delta = pool_b_current_liquidity as i64 - pool_b_initial_liquidity as i64;
-
-    if pool_a_delta + amount_in as i64 == 0 && pool_b_delta >= 0 {
-        msg!("Type: Equalizing");
-        return Ok(fee_out);
-    }
+    let fee_out = pool_b.base_fee;
+    // Liquidity lent out by a flash loan doesn't count as an imbalance
+    let pool_a_delta = pool_value(pool_a)? as i64 - pool_a.initial_liquidity as i64;
+    let pool_b_delta = pool_value(pool_b)? as i64 - pool_b.initial_liquidity as i64;
 
     if pool_a_delta > pool_b_delta && pool_b_delta < 0 {
         msg!("Type: Imbalancing");
-        let liquidity_usage_ratio = amount_out as f64 / pool_b_current_liquidity as f64;
-        let scaled_fee = (10_000.0 * (1.0 - (-5.0 * liquidity_usage_ratio).exp())).round() as u64;
-        return Ok(scaled_fee.clamp(fee_out, 10_000));
+        let scaled_fee = curve_fee(&pool_b.fee_curve, &pool_b.imbalance_fee, amount_out, pool_b.current_liquidity)?;
+        return Ok(scaled_fee.clamp(fee_out, pool_b.imbalance_fee.max_fee));
     }
     
     msg!("Type: Regular");
-    let liquidity_usage_ratio = amount_out as f64 / pool_b_current_liquidity as f64;
-    let scaled_fee = (1_000.0 * (1.0 - (-1.0 * liquidity_usage_ratio).exp())).round() as u64;
-    Ok(scaled_fee.clamp(fee_out, 1_000))
+    let scaled_fee = curve_fee(&pool_b.fee_curve, &pool_b.regular_fee, amount_out, pool_b.current_liquidity)?;
+    Ok(scaled_fee.clamp(fee_out, pool_b.regular_fee.max_fee))
+}
+
+// Charged in pool A tokens on the part of amount_in that lands above pool A's target,
+// at a rate set by how far above target pool A ends up
+pub fn input_fee(pool_a: &Pool, amount_in: u64) -> Result<u64, SpiceError> {
+    let pool_a_delta = pool_value(pool_a)? as i128 - pool_a.initial_liquidity as i128;
+    let pool_a_surplus = pool_a_delta + amount_in as i128;
+
+    if pool_a_surplus <= 0 {
+        return Ok(0);
+    }
+
+    let surplus_in = pool_a_surplus.min(amount_in as i128) as u64;
+
+    let liquidity = pool_a.current_liquidity
+        .checked_add(amount_in)
+        .ok_or(SpiceError::Overflow)?;
+
+    let scaled_fee = curve_fee(&pool_a.fee_curve, &pool_a.regular_fee, pool_a_surplus as u64, liquidity)?
+        .min(pool_a.regular_fee.max_fee);
+
+    let fee = u128::from(surplus_in)
+        .checked_mul(u128::from(scaled_fee))
+        .and_then(|num| num.checked_div(FEE_SCALE))
+        .ok_or(SpiceError::Overflow)?;
+
+    Ok(fee as u64)
 }
\ No newline at end of file
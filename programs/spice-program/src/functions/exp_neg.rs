@@ -0,0 +1,67 @@
+use crate::constants::FIXED_POINT_SCALE;
+
+// e^-n scaled 1:FIXED_POINT_SCALE, e^-29 and below round to zero
+const EXP_NEG_INT: [u128; 29] = [
+    1_000_000_000_000,
+    367_879_441_171,
+    135_335_283_237,
+    49_787_068_368,
+    18_315_638_889,
+    6_737_946_999,
+    2_478_752_177,
+    911_881_966,
+    335_462_628,
+    123_409_804,
+    45_399_930,
+    16_701_701,
+    6_144_212,
+    2_260_329,
+    831_529,
+    305_902,
+    112_535,
+    41_399,
+    15_230,
+    5_603,
+    2_061,
+    758,
+    279,
+    103,
+    38,
+    14,
+    5,
+    2,
+    1,
+];
+
+const TAYLOR_TERMS: u128 = 20;
+const TAYLOR_PRECISION: u128 = 1_000_000;
+
+// e^-x for x scaled 1:FIXED_POINT_SCALE, the integer part from EXP_NEG_INT and the fraction from a Taylor series.
+// Absolute error stays below 2 / FIXED_POINT_SCALE, within one unit of FEE_SCALE of the f64 fee curve
+pub fn exp_neg(x: u128) -> u128 {
+    let integer = x / FIXED_POINT_SCALE;
+    if integer >= EXP_NEG_INT.len() as u128 {
+        return 0;
+    }
+
+    // Series is evaluated with TAYLOR_PRECISION extra digits
+    let one = FIXED_POINT_SCALE * TAYLOR_PRECISION;
+    let fraction = x % FIXED_POINT_SCALE * TAYLOR_PRECISION;
+
+    // e^-f = sum (-f)^i / i!, terms alternate in sign
+    let mut sum = one as i128;
+    let mut term = one;
+    for i in 1..=TAYLOR_TERMS {
+        term = term * fraction / one / i;
+        if term == 0 {
+            break;
+        }
+        if i % 2 == 1 {
+            sum -= term as i128;
+        } else {
+            sum += term as i128;
+        }
+    }
+
+    (EXP_NEG_INT[integer as usize] * (sum as u128 / TAYLOR_PRECISION) + FIXED_POINT_SCALE / 2) / FIXED_POINT_SCALE
+}
\ No newline at end of file
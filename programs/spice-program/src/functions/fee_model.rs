@@ -0,0 +1,70 @@
+use crate::{
+    constants::{FEE_SCALE, FIXED_POINT_SCALE, SPICE_SCALE},
+    errors::SpiceError,
+    functions::exp_neg,
+    states::{DynamicFeeParams, FeeBreakpoint, FeeCurve},
+};
+
+// max_fee * shape(steepness * amount_out / liquidity)
+pub fn curve_fee(
+    curve: &FeeCurve,
+    params: &DynamicFeeParams,
+    amount_out: u64,
+    liquidity: u64,
+) -> Result<u64, SpiceError> {
+    if liquidity == 0 {
+        return Err(SpiceError::NoLiquidity);
+    }
+
+    let usage = u128::from(params.steepness)
+        .checked_mul(u128::from(amount_out))
+        .and_then(|num| num.checked_mul(FIXED_POINT_SCALE))
+        .and_then(|num| num.checked_div(u128::from(SPICE_SCALE) * u128::from(liquidity)))
+        .ok_or(SpiceError::Overflow)?;
+
+    let saturation = match curve {
+        FeeCurve::Exponential => exponential_saturation(usage),
+        FeeCurve::Linear => linear_saturation(usage),
+        FeeCurve::PiecewiseLinear { breakpoints, count } => {
+            piecewise_saturation(&breakpoints[..*count as usize], usage)
+        }
+    };
+
+    let scaled_fee = u128::from(params.max_fee)
+        .checked_mul(saturation)
+        .map(|num| (num + FIXED_POINT_SCALE / 2) / FIXED_POINT_SCALE)
+        .ok_or(SpiceError::Overflow)?;
+
+    Ok(scaled_fee as u64)
+}
+
+// 1 - e^-x
+fn exponential_saturation(usage: u128) -> u128 {
+    FIXED_POINT_SCALE - exp_neg(usage)
+}
+
+// min(x, 1)
+fn linear_saturation(usage: u128) -> u128 {
+    usage.min(FIXED_POINT_SCALE)
+}
+
+// Interpolates between (0, 0) and the breakpoints, flat after the last one
+fn piecewise_saturation(breakpoints: &[FeeBreakpoint], usage: u128) -> u128 {
+    let mut previous_usage = 0;
+    let mut previous_fee = 0;
+
+    for breakpoint in breakpoints {
+        let breakpoint_usage = u128::from(breakpoint.usage) * FIXED_POINT_SCALE / u128::from(SPICE_SCALE);
+        let breakpoint_fee = u128::from(breakpoint.fee) * FIXED_POINT_SCALE / FEE_SCALE;
+
+        if usage <= breakpoint_usage {
+            return previous_fee
+                + (breakpoint_fee - previous_fee) * (usage - previous_usage) / (breakpoint_usage - previous_usage);
+        }
+
+        previous_usage = breakpoint_usage;
+        previous_fee = breakpoint_fee;
+    }
+
+    previous_fee
+}
\ No newline at end of file
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::Pubkey;
+
+use crate::{constants::{FEE_SCALE, MAX_FEE_RECIPIENTS}, errors::SpiceError, states::{FeeRecipient, FeeSplit, Pool}};
+
+// Weights of distinct recipients may add up to at most FEE_SCALE, the rest stays with the pool
+pub fn check_fee_split(recipients: &[FeeRecipient]) -> Result<(), SpiceError> {
+    if recipients.is_empty() || recipients.len() > MAX_FEE_RECIPIENTS {
+        return Err(SpiceError::InvalidFeeSplit);
+    }
+
+    let mut total_weight: u128 = 0;
+    for (i, recipient) in recipients.iter().enumerate() {
+        if recipient.address == Pubkey::default()
+            || recipient.weight == 0
+            || recipients[..i].iter().any(|other| other.address == recipient.address)
+        {
+            return Err(SpiceError::InvalidFeeSplit);
+        }
+        total_weight += u128::from(recipient.weight);
+    }
+
+    if total_weight > FEE_SCALE {
+        return Err(SpiceError::InvalidFeeSplit);
+    }
+
+    Ok(())
+}
+
+// Credits the recipients' shares of newly accrued protocol income at the current weights
+pub fn split_protocol_fee(pool: &mut Pool, fee_split: &FeeSplit, protocol_fee: u64) -> Result<u64, SpiceError> {
+    let mut split = 0;
+
+    for recipient in &fee_split.recipients[..fee_split.count as usize] {
+        let amount = (u128::from(protocol_fee) * u128::from(recipient.weight) / FEE_SCALE) as u64;
+        if amount == 0 {
+            continue;
+        }
+
+        // Without a free slot the share stays with the pool rather than failing the trade
+        let share = match pool.fee_shares.iter().position(|share| share.amount > 0 && share.recipient == recipient.address) {
+            Some(slot) => &mut pool.fee_shares[slot],
+            None => match pool.fee_shares.iter_mut().find(|share| share.amount == 0) {
+                Some(share) => share,
+                None => continue,
+            },
+        };
+
+        share.recipient = recipient.address;
+        share.amount = share.amount
+            .checked_add(amount)
+            .ok_or(SpiceError::Overflow)?;
+        split += amount;
+    }
+
+    Ok(split)
+}
+
+pub fn take_fee_share(pool: &mut Pool, recipient: Pubkey) -> Result<u64, SpiceError> {
+    let share = pool.fee_shares
+        .iter_mut()
+        .find(|share| share.amount > 0 && share.recipient == recipient)
+        .ok_or(SpiceError::NoFeeShare)?;
+
+    let amount = share.amount;
+    share.amount = 0;
+
+    Ok(amount)
+}
@@ -0,0 +1,30 @@
+use anchor_lang::{prelude::Pubkey, solana_program::instruction::Instruction, Discriminator};
+
+use crate::{constants::FEE_SCALE, errors::SpiceError, instruction::FlashRepay};
+
+// Position of pool_pda in FlashRepayInstructionAccounts
+pub const FLASH_REPAY_POOL_INDEX: usize = 3;
+
+pub fn is_flash_repay(instruction: &Instruction, pool: &Pubkey) -> bool {
+    instruction.program_id == crate::ID
+        && instruction.data.get(..8) == Some(&FlashRepay::DISCRIMINATOR[..])
+        && instruction.accounts.get(FLASH_REPAY_POOL_INDEX).map(|account| account.pubkey) == Some(*pool)
+}
+
+// Takes the instructions following flash_borrow in the transaction
+pub fn check_flash_repay(mut instructions: impl Iterator<Item = Instruction>, pool: &Pubkey) -> Result<(), SpiceError> {
+    if instructions.any(|instruction| is_flash_repay(&instruction, pool)) {
+        return Ok(());
+    }
+
+    Err(SpiceError::FlashLoanNotRepaid)
+}
+
+pub fn flash_loan_fee(pool_flash_loan_fee: u64, amount: u64) -> Result<u64, SpiceError> {
+    let fee = u128::from(amount)
+        .checked_mul(u128::from(pool_flash_loan_fee))
+        .and_then(|num| num.checked_div(FEE_SCALE))
+        .ok_or(SpiceError::Overflow)?;
+
+    Ok(fee as u64)
+}
\ No newline at end of file
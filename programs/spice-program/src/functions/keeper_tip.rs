@@ -0,0 +1,13 @@
+use crate::{constants::BPS_SCALE, errors::SpiceError, states::Provider};
+
+// Moves the keeper's share out of the provider's pending yield
+pub fn take_keeper_tip(provider: &mut Provider) -> Result<u64, SpiceError> {
+    if !provider.keeper_enabled {
+        return Err(SpiceError::KeeperNotEnabled);
+    }
+
+    let tip = (u128::from(provider.pending_claim) * u128::from(provider.keeper_tip) / BPS_SCALE) as u64;
+    provider.pending_claim -= tip;
+
+    Ok(tip)
+}
\ No newline at end of file
@@ -0,0 +1,77 @@
+use crate::{constants::{LOCK_TERMS, SPICE_SCALE}, errors::SpiceError, states::{Pool, Provider}};
+
+// Locked LP earns yield as if it were lp_boost LP larger, unlocked providers are diluted through the pool total
+pub fn effective_lp_supply(pool: &Pool) -> Result<u64, SpiceError> {
+    pool.lp_supply.checked_add(pool.total_lp_boost).ok_or(SpiceError::Overflow)
+}
+
+// The boost stops counting once the lock has run out, even before it is unlocked
+pub fn effective_lp_balance(provider: &Provider, now: i64) -> Result<u64, SpiceError> {
+    if now >= provider.lock_end {
+        return Ok(provider.lp_balance);
+    }
+
+    provider.lp_balance.checked_add(provider.lp_boost).ok_or(SpiceError::Overflow)
+}
+
+pub fn lock_boost(lock_duration: i64) -> Result<u64, SpiceError> {
+    LOCK_TERMS
+        .iter()
+        .find(|(duration, _)| *duration == lock_duration)
+        .map(|(_, boost)| *boost)
+        .ok_or(SpiceError::InvalidLockTerm)
+}
+
+fn set_lp_boost(pool: &mut Pool, provider: &mut Provider, lp_boost: u64) -> Result<(), SpiceError> {
+    pool.total_lp_boost = pool.total_lp_boost
+        .checked_sub(provider.lp_boost)
+        .and_then(|total| total.checked_add(lp_boost))
+        .ok_or(SpiceError::Overflow)?;
+    provider.lp_boost = lp_boost;
+
+    Ok(())
+}
+
+// Yield must be settled before the boost changes
+pub fn expire_lp_boost(pool: &mut Pool, provider: &mut Provider, now: i64) -> Result<(), SpiceError> {
+    if provider.lp_boost > 0 && now >= provider.lock_end {
+        set_lp_boost(pool, provider, 0)?;
+    }
+
+    Ok(())
+}
+
+pub fn lock_provider(pool: &mut Pool, provider: &mut Provider, amount: u64, lock_duration: i64, now: i64) -> Result<(), SpiceError> {
+    let boost = lock_boost(lock_duration)?;
+
+    if amount == 0 || amount > provider.lp_balance - provider.locked_lp {
+        return Err(SpiceError::InvalidLpAmount);
+    }
+
+    // Adding to a running lock may not shorten it, the whole locked amount takes the new term's boost
+    let lock_end = now.checked_add(lock_duration).ok_or(SpiceError::Overflow)?;
+    if lock_end < provider.lock_end {
+        return Err(SpiceError::InvalidLockTerm);
+    }
+
+    provider.locked_lp += amount;
+    provider.lock_end = lock_end;
+
+    let lp_boost = u128::from(provider.locked_lp) * u128::from(boost - SPICE_SCALE) / u128::from(SPICE_SCALE);
+    set_lp_boost(pool, provider, lp_boost as u64)
+}
+
+pub fn unlock_provider(pool: &mut Pool, provider: &mut Provider, now: i64) -> Result<u64, SpiceError> {
+    if now < provider.lock_end {
+        return Err(SpiceError::LockNotExpired);
+    }
+    if provider.locked_lp == 0 {
+        return Err(SpiceError::InvalidLpAmount);
+    }
+
+    let amount = provider.locked_lp;
+    provider.locked_lp = 0;
+    set_lp_boost(pool, provider, 0)?;
+
+    Ok(amount)
+}
\ No newline at end of file
@@ -0,0 +1,18 @@
+use anchor_spl::metadata::mpl_token_metadata::{MAX_NAME_LENGTH, MAX_SYMBOL_LENGTH};
+
+fn truncate(value: String, max_length: usize) -> String {
+    let mut value = value;
+    while value.len() > max_length {
+        value.pop();
+    }
+    value
+}
+
+// Metaplex pads name and symbol with null bytes
+pub fn lp_token_name(underlying_name: &str) -> String {
+    truncate(format!("Spice {} LP", underlying_name.trim_matches('\0').trim()), MAX_NAME_LENGTH)
+}
+
+pub fn lp_token_symbol(underlying_symbol: &str) -> String {
+    truncate(format!("sp{}", underlying_symbol.trim_matches('\0').trim()), MAX_SYMBOL_LENGTH)
+}
\ No newline at end of file
@@ -0,0 +1,53 @@
+use crate::{constants::MINIMUM_LP_SUPPLY, errors::SpiceError, states::Pool};
+
+// Liquidity lent out by an active flash loan still belongs to the pool
+pub fn pool_value(pool: &Pool) -> Result<u64, SpiceError> {
+    pool.current_liquidity
+        .checked_add(pool.flash_loan_amount)
+        .ok_or(SpiceError::Overflow)
+}
+
+pub fn deposit_liquidity(pool: &mut Pool, amount: u64) -> Result<u64, SpiceError> {
+    let value = pool_value(pool)?;
+
+    // The first mint prices LP 1:1 against the whole pool value and locks MINIMUM_LP_SUPPLY, or any
+    // value left without LP, in the supply for good. Donations can't inflate the LP price and
+    // leftover value is never handed to the first depositor
+    let (lp_amount, minted) = if pool.lp_supply == 0 {
+        let minted = amount.checked_add(value).ok_or(SpiceError::Overflow)?;
+        (minted.saturating_sub(MINIMUM_LP_SUPPLY.max(value)), minted)
+    } else if value == 0 {
+        return Err(SpiceError::NoLiquidity);
+    } else {
+        let lp_amount = (u128::from(amount) * u128::from(pool.lp_supply) / u128::from(value)) as u64;
+        (lp_amount, lp_amount)
+    };
+
+    if lp_amount == 0 {
+        return Err(SpiceError::InvalidLpAmount);
+    }
+
+    pool.lp_supply = pool.lp_supply.checked_add(minted).ok_or(SpiceError::Overflow)?;
+    pool.initial_liquidity = pool.initial_liquidity.checked_add(amount).ok_or(SpiceError::Overflow)?;
+    pool.current_liquidity = pool.current_liquidity.checked_add(amount).ok_or(SpiceError::Overflow)?;
+
+    Ok(lp_amount)
+}
+
+pub fn withdraw_liquidity(pool: &mut Pool, lp_amount: u64) -> Result<u64, SpiceError> {
+    if lp_amount > pool.lp_supply {
+        return Err(SpiceError::InvalidLpAmount);
+    }
+
+    let value = pool_value(pool)?;
+    let amount = (u128::from(lp_amount) * u128::from(value) / u128::from(pool.lp_supply)) as u64;
+    let target = (u128::from(lp_amount) * u128::from(pool.initial_liquidity) / u128::from(pool.lp_supply)) as u64;
+
+    pool.lp_supply -= lp_amount;
+    pool.initial_liquidity -= target;
+    pool.current_liquidity = pool.current_liquidity
+        .checked_sub(amount)
+        .ok_or(SpiceError::InsufficientLiquidity)?;
+
+    Ok(amount)
+}
\ No newline at end of file
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::Pubkey;
+
+use crate::{constants::{FEE_SCALE, MAX_FEE_SHARES, MAX_REWARD_STREAMS, POOL_VERSION, SPICE_SCALE}, errors::SpiceError, states::{DynamicFeeParams, FeeCurve, FeeShare, LegacyPool, Pool, RewardStream, WithdrawalFeeParams}};
+
+// Settings every pool starts with, whether created or migrated
+pub fn new_pool(is_active: bool, base_fee: u64, mint: Pubkey, pyth_price_feed_account: Pubkey, lp_token_mint: Pubkey) -> Pool {
+    Pool {
+        is_active,
+        base_fee,
+        mint,
+        pyth_price_feed_account,
+        lp_token_mint,
+        initial_liquidity: 0,
+        cumulative_yield: 0,
+        current_liquidity: 0,
+        protocol_income: 0,
+        flash_loan_fee: 0,
+        flash_loan_amount: 0,
+        imbalance_fee: DynamicFeeParams { max_fee: 10_000, steepness: 5 * SPICE_SCALE },
+        regular_fee: DynamicFeeParams { max_fee: 1_000, steepness: SPICE_SCALE },
+        fee_curve: FeeCurve::Exponential,
+        rebate_rate: 0,
+        max_rebate_per_trade: 0,
+        max_rebate_per_epoch: 0,
+        rebate_epoch: 0,
+        epoch_rebates: 0,
+        lp_supply: 0,
+        total_lp_boost: 0,
+        unsynced_lp: 0,
+        reward_streams: [RewardStream::default(); MAX_REWARD_STREAMS],
+        reward_stream_nonce: 0,
+        deficit_coverage: FEE_SCALE as u64,
+        fee_shares: [FeeShare::default(); MAX_FEE_SHARES],
+        protocol_share: None,
+        withdrawal_fee: WithdrawalFeeParams { max_fee: 5_000, depth_steepness: SPICE_SCALE / 10, size_steepness: SPICE_SCALE },
+        version: POOL_VERSION,
+    }
+}
+
+// Legacy LP was minted 1:1 with deposits, the LP mint supply becomes the pool's lp_supply.
+// Legacy protocol income is a scaled running total that collecting never reset, and all of it was
+// counted in current_liquidity. It moves out of the liquidity, less collected_income already paid out
+pub fn migrate_legacy_pool(legacy: &LegacyPool, lp_supply: u64, collected_income: u64) -> Result<Pool, SpiceError> {
+    let accrued_income = legacy.protocol_income / SPICE_SCALE;
+
+    let protocol_income = accrued_income
+        .checked_sub(collected_income)
+        .ok_or(SpiceError::InvalidProtocolIncomeAmount)?;
+
+    let current_liquidity = legacy.current_liquidity
+        .checked_sub(accrued_income)
+        .ok_or(SpiceError::InsufficientLiquidity)?;
+
+    Ok(Pool {
+        initial_liquidity: legacy.initial_liquidity,
+        cumulative_yield: legacy.cumulative_yield,
+        current_liquidity,
+        protocol_income,
+        lp_supply,
+        ..new_pool(legacy.is_active, legacy.base_fee, legacy.mint, legacy.pyth_price_feed_account, legacy.lp_token_mint)
+    })
+}
@@ -0,0 +1,22 @@
+use crate::{errors::SpiceError, functions::{settle_rewards, settle_yield}, states::{LegacyProvider, Pool, Provider}};
+
+// Legacy yield is settled on the recorded balance, the position then holds the lp_amount moved into it.
+// Reward streams are checkpointed on an empty balance, legacy positions never earned any
+pub fn migrate_legacy_provider(pool: &Pool, legacy: &LegacyProvider, index: u8, lp_amount: u64, now: i64) -> Result<Provider, SpiceError> {
+    let mut provider = Provider {
+        address: legacy.address,
+        index,
+        lp_balance: legacy.lp_balance,
+        last_cumulative_yield: legacy.last_cumulative_yield,
+        pending_claim: legacy.pending_claim,
+        ..Default::default()
+    };
+
+    settle_yield(pool, &mut provider, now)?;
+
+    provider.lp_balance = 0;
+    settle_rewards(pool, &mut provider)?;
+    provider.lp_balance = lp_amount;
+
+    Ok(provider)
+}
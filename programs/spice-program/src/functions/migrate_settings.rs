@@ -0,0 +1,10 @@
+use crate::constants::FEE_SCALE;
+
+// An income divisor d gave the protocol 1/d of each fee
+pub fn protocol_share_from_divisor(income_distribution: u64) -> u64 {
+    if income_distribution == 0 {
+        return 0;
+    }
+
+    (FEE_SCALE / u128::from(income_distribution)) as u64
+}
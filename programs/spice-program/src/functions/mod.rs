@@ -4,10 +4,56 @@ pub use dynamic_fee::*;
 pub use calculate_yield::*;
 pub use chekers::*;
 pub use transfer::*;
+pub use distribute_fee::*;
+pub use quote_swap::*;
+pub use settle_swap::*;
+pub use exp_neg::*;
+pub use fee_model::*;
+pub use rebalancing_rebate::*;
+pub use lp_shares::*;
+pub use settle_provider::*;
+pub use keeper_tip::*;
+pub use lock_liquidity::*;
+pub use reward_streams::*;
+pub use lp_metadata::*;
+pub use position_registry::*;
+pub use withdraw_into::*;
+pub use deficit_coverage::*;
+pub use protocol_income::*;
+pub use fee_split::*;
+pub use migrate_settings::*;
+pub use withdrawal_fee::*;
+pub use migrate_pool::*;
+pub use migrate_provider::*;
+pub use flash_loan::*;
+pub use dca_order::*;
 
 pub mod raw_amount_out;
 pub mod net_amount_out;
 pub mod dynamic_fee;
 pub mod calculate_yield;
 pub mod chekers;
-pub mod transfer;
\ No newline at end of file
+pub mod transfer;
+pub mod distribute_fee;
+pub mod quote_swap;
+pub mod settle_swap;
+pub mod exp_neg;
+pub mod fee_model;
+pub mod rebalancing_rebate;
+pub mod lp_shares;
+pub mod settle_provider;
+pub mod keeper_tip;
+pub mod lock_liquidity;
+pub mod reward_streams;
+pub mod lp_metadata;
+pub mod position_registry;
+pub mod withdraw_into;
+pub mod deficit_coverage;
+pub mod protocol_income;
+pub mod fee_split;
+pub mod migrate_settings;
+pub mod withdrawal_fee;
+pub mod migrate_pool;
+pub mod migrate_provider;
+pub mod flash_loan;
+pub mod dca_order;
\ No newline at end of file
@@ -0,0 +1,25 @@
+use crate::{constants::MAX_POSITIONS, errors::SpiceError, states::PositionRegistry};
+
+pub fn register_position(registry: &mut PositionRegistry, index: u8) -> Result<(), SpiceError> {
+    if index >= MAX_POSITIONS {
+        return Err(SpiceError::InvalidPositionIndex);
+    }
+
+    registry.positions |= 1 << index;
+
+    Ok(())
+}
+
+pub fn unregister_position(registry: &mut PositionRegistry, index: u8) -> Result<(), SpiceError> {
+    if index >= MAX_POSITIONS {
+        return Err(SpiceError::InvalidPositionIndex);
+    }
+
+    registry.positions &= !(1 << index);
+
+    Ok(())
+}
+
+pub fn position_indices(registry: &PositionRegistry) -> Vec<u8> {
+    (0..MAX_POSITIONS).filter(|index| registry.positions & (1 << index) != 0).collect()
+}
\ No newline at end of file
@@ -0,0 +1,18 @@
+use crate::{errors::SpiceError, functions::pool_value, states::Pool};
+
+// Takes up to the accrued protocol income, all of it when no amount is given
+pub fn take_protocol_income(pool: &mut Pool, amount: Option<u64>) -> Result<u64, SpiceError> {
+    // Protocol income backs the pool until it is back at its target
+    if pool_value(pool)? < pool.initial_liquidity {
+        return Err(SpiceError::PoolInDeficit);
+    }
+
+    let amount = amount.unwrap_or(pool.protocol_income);
+    if amount == 0 || amount > pool.protocol_income {
+        return Err(SpiceError::InvalidProtocolIncomeAmount);
+    }
+
+    pool.protocol_income -= amount;
+
+    Ok(amount)
+}
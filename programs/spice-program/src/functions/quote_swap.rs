@@ -0,0 +1,45 @@
+use crate::{errors::SpiceError, functions::{dynamic_fee, input_fee, net_amount_out, raw_amount_out, rebalancing_rebate}, states::Pool};
+
+pub struct SwapQuote {
+    pub input_fee: u64,
+    pub raw_amount_out: u64,
+    pub amount_out: u64,
+    pub protocol_fee: u64,
+    pub partner_fee: u64,
+    pub rebate: u64,
+}
+
+pub fn quote_swap(
+    pool_a: &Pool,
+    pool_b: &Pool,
+    amount_in: u64,
+    prices: (u64, u64),
+    decimals: (u8, u8),
+    partner_fee: u64,
+    epoch: u64,
+) -> Result<SwapQuote, SpiceError> {
+    let input_fee = input_fee(pool_a, amount_in)?;
+
+    let raw_amount_out = raw_amount_out(
+        amount_in - input_fee,
+        prices.0,
+        prices.1,
+        decimals.0,
+        decimals.1
+    )?;
+
+    let fee = dynamic_fee(pool_a, pool_b, raw_amount_out as u64)?;
+
+    let (amount_out, protocol_fee, partner_fee) = net_amount_out(raw_amount_out, fee, partner_fee)?;
+
+    let rebate = rebalancing_rebate(pool_a, pool_b, amount_in, raw_amount_out as u64, epoch)?;
+
+    Ok(SwapQuote {
+        input_fee,
+        raw_amount_out: raw_amount_out as u64,
+        amount_out: amount_out + rebate,
+        protocol_fee,
+        partner_fee,
+        rebate,
+    })
+}
\ No newline at end of file
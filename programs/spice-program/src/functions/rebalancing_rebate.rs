@@ -0,0 +1,57 @@
+use crate::{constants::FEE_SCALE, errors::SpiceError, functions::pool_value, states::Pool};
+
+// Paid in pool B tokens out of pool B's protocol income for the part of the
+// trade that moves either pool back toward its initial liquidity
+pub fn rebalancing_rebate(
+    pool_a: &Pool,
+    pool_b: &Pool,
+    amount_in: u64,
+    raw_amount_out: u64,
+    epoch: u64,
+) -> Result<u64, SpiceError> {
+    if pool_b.rebate_rate == 0 || amount_in == 0 {
+        return Ok(0);
+    }
+
+    let pool_a_deficit = pool_a.initial_liquidity.saturating_sub(pool_value(pool_a)?);
+    let pool_b_surplus = pool_value(pool_b)?.saturating_sub(pool_b.initial_liquidity);
+
+    // Deficit filled in pool A, valued in pool B tokens
+    let restored_a = u128::from(raw_amount_out)
+        .checked_mul(u128::from(amount_in.min(pool_a_deficit)))
+        .and_then(|num| num.checked_div(u128::from(amount_in)))
+        .ok_or(SpiceError::Overflow)?;
+
+    let restored_b = u128::from(raw_amount_out.min(pool_b_surplus));
+
+    let rebate = (restored_a + restored_b)
+        .checked_mul(u128::from(pool_b.rebate_rate))
+        .and_then(|num| num.checked_div(FEE_SCALE))
+        .ok_or(SpiceError::Overflow)?;
+
+    let epoch_rebates = if pool_b.rebate_epoch == epoch { pool_b.epoch_rebates } else { 0 };
+    let epoch_remaining = pool_b.max_rebate_per_epoch.saturating_sub(epoch_rebates);
+    let available_income = pool_b.protocol_income;
+
+    Ok((rebate as u64)
+        .min(pool_b.max_rebate_per_trade)
+        .min(epoch_remaining)
+        .min(available_income))
+}
+
+pub fn pay_rebate(pool_b: &mut Pool, rebate: u64, epoch: u64) -> Result<(), SpiceError> {
+    if pool_b.rebate_epoch != epoch {
+        pool_b.rebate_epoch = epoch;
+        pool_b.epoch_rebates = 0;
+    }
+
+    pool_b.epoch_rebates = pool_b.epoch_rebates
+        .checked_add(rebate)
+        .ok_or(SpiceError::Overflow)?;
+
+    pool_b.protocol_income = pool_b.protocol_income
+        .checked_sub(rebate)
+        .ok_or(SpiceError::Overflow)?;
+
+    Ok(())
+}
\ No newline at end of file
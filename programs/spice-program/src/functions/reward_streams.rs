@@ -0,0 +1,151 @@
+use anchor_lang::prelude::Pubkey;
+
+use crate::{constants::{ADMIN_REWARD_SLOTS, FIXED_POINT_SCALE, MAX_REWARD_DURATION, MAX_REWARD_STREAMS, MIN_REWARD_RATE, REWARD_CLAIM_WINDOW}, errors::SpiceError, states::{Pool, Provider, RewardDebt, RewardStream}};
+
+// Must run before lp_supply or any provider's lp_balance changes
+pub fn update_reward_streams(pool: &mut Pool, now: i64) -> Result<(), SpiceError> {
+    let lp_supply = pool.lp_supply;
+
+    for stream in pool.reward_streams.iter_mut() {
+        if stream.mint == Pubkey::default() {
+            continue;
+        }
+
+        let from = stream.last_update.max(stream.start);
+        let to = now.min(stream.end);
+        if to <= from {
+            continue;
+        }
+
+        // Emissions while the pool has no LP are not distributed
+        if lp_supply > 0 {
+            let reward = ((to - from) as u64).checked_mul(stream.rate).ok_or(SpiceError::Overflow)?;
+
+            stream.reward_per_share = stream.reward_per_share
+                .checked_add(u128::from(reward) * FIXED_POINT_SCALE / u128::from(lp_supply))
+                .ok_or(SpiceError::Overflow)?;
+            stream.distributed = stream.distributed.checked_add(reward).ok_or(SpiceError::Overflow)?;
+        }
+
+        stream.last_update = to;
+    }
+
+    Ok(())
+}
+
+pub fn settle_rewards(pool: &Pool, provider: &mut Provider) -> Result<(), SpiceError> {
+    for (stream, debt) in pool.reward_streams.iter().zip(provider.reward_debts.iter_mut()) {
+        if stream.mint == Pubkey::default() {
+            continue;
+        }
+
+        // The slot holds a stream this position has not seen yet, rewards left from a replaced stream are forfeited
+        if debt.stream_id != stream.id {
+            *debt = RewardDebt { stream_id: stream.id, ..Default::default() };
+        }
+
+        let earned = (stream.reward_per_share - debt.reward_per_share) * u128::from(provider.lp_balance) / FIXED_POINT_SCALE;
+
+        debt.pending = debt.pending.checked_add(earned as u64).ok_or(SpiceError::Overflow)?;
+        debt.reward_per_share = stream.reward_per_share;
+    }
+
+    Ok(())
+}
+
+// A finished stream keeps its slot until providers had REWARD_CLAIM_WINDOW to claim
+pub fn is_reward_slot_free(stream: &RewardStream, now: i64) -> bool {
+    stream.mint == Pubkey::default() || now >= stream.end.saturating_add(REWARD_CLAIM_WINDOW)
+}
+
+// The last ADMIN_REWARD_SLOTS slots are kept for the admin, permissionless funding can't fill them
+pub fn free_reward_slot(pool: &Pool, now: i64) -> Result<usize, SpiceError> {
+    pool.reward_streams[..MAX_REWARD_STREAMS - ADMIN_REWARD_SLOTS]
+        .iter()
+        .position(|stream| is_reward_slot_free(stream, now))
+        .ok_or(SpiceError::NoFreeRewardSlot)
+}
+
+// Returns the amount the stream has to be funded with
+pub fn add_reward_stream(pool: &mut Pool, slot: usize, funder: Pubkey, mint: Pubkey, rate: u64, schedule: (i64, i64), now: i64) -> Result<u64, SpiceError> {
+    let (start, end) = schedule;
+
+    if slot >= MAX_REWARD_STREAMS || mint == Pubkey::default() || rate < MIN_REWARD_RATE || start < now || end <= start {
+        return Err(SpiceError::InvalidRewardStream);
+    }
+    // A slot can be held for at most MAX_REWARD_DURATION plus the claim window
+    if end - now > MAX_REWARD_DURATION {
+        return Err(SpiceError::InvalidRewardStream);
+    }
+    // Rewards are escrowed in the treasury ATA of their mint, which must not be one holding liquidity
+    if mint == pool.mint || mint == pool.lp_token_mint {
+        return Err(SpiceError::InvalidRewardStream);
+    }
+    if !is_reward_slot_free(&pool.reward_streams[slot], now) {
+        return Err(SpiceError::NoFreeRewardSlot);
+    }
+    // The replaced stream's undistributed part must have gone back to its funder
+    if pool.reward_streams[slot].funding != pool.reward_streams[slot].distributed {
+        return Err(SpiceError::RewardStreamNotRefunded);
+    }
+
+    let funding = ((end - start) as u64).checked_mul(rate).ok_or(SpiceError::Overflow)?;
+
+    pool.reward_stream_nonce += 1;
+    pool.reward_streams[slot] = RewardStream {
+        id: pool.reward_stream_nonce,
+        mint,
+        rate,
+        start,
+        end,
+        last_update: start,
+        reward_per_share: 0,
+        distributed: 0,
+        funder,
+        funding,
+    };
+
+    Ok(funding)
+}
+
+// Emissions that were never distributed, because the pool had no LP, go back to the funder
+pub fn refund_reward_stream(pool: &mut Pool, slot: usize, now: i64) -> Result<u64, SpiceError> {
+    let stream = pool.reward_streams.get_mut(slot).ok_or(SpiceError::InvalidRewardStream)?;
+
+    if stream.mint == Pubkey::default() {
+        return Err(SpiceError::InvalidRewardStream);
+    }
+    if now < stream.end || stream.last_update < stream.end {
+        return Err(SpiceError::RewardStreamNotEnded);
+    }
+
+    let refund = stream.funding - stream.distributed;
+    stream.funding = stream.distributed;
+
+    Ok(refund)
+}
+
+// A free slot is reused without waiting for the funder, the replaced stream is refunded first
+pub fn refund_replaced_stream(pool: &mut Pool, slot: usize, now: i64) -> Result<u64, SpiceError> {
+    let stream = pool.reward_streams.get(slot).ok_or(SpiceError::InvalidRewardStream)?;
+
+    if stream.mint == Pubkey::default() || stream.funding == stream.distributed {
+        return Ok(0);
+    }
+
+    refund_reward_stream(pool, slot, now)
+}
+
+pub fn take_rewards(pool: &Pool, provider: &mut Provider, slot: usize) -> Result<u64, SpiceError> {
+    let stream = pool.reward_streams.get(slot).ok_or(SpiceError::InvalidRewardStream)?;
+    let debt = &mut provider.reward_debts[slot];
+
+    if stream.mint == Pubkey::default() || debt.stream_id != stream.id {
+        return Err(SpiceError::InvalidRewardStream);
+    }
+
+    let amount = debt.pending;
+    debt.pending = 0;
+
+    Ok(amount)
+}
\ No newline at end of file
@@ -0,0 +1,147 @@
+use anchor_lang::prelude::Pubkey;
+
+use crate::{errors::SpiceError, functions::{calculate_yield, cover_deficit, deposit_liquidity, effective_lp_balance, effective_lp_supply, expire_lp_boost, settle_rewards}, states::{Pool, Provider}};
+
+pub fn settle_yield(pool: &Pool, provider: &mut Provider, now: i64) -> Result<(), SpiceError> {
+    let provider_income = calculate_yield(
+        pool.cumulative_yield,
+        effective_lp_supply(pool)?,
+        effective_lp_balance(provider, now)?,
+        provider.last_cumulative_yield,
+    )?;
+
+    provider.pending_claim = provider.pending_claim
+        .checked_add(provider_income)
+        .ok_or(SpiceError::Overflow)?;
+    provider.last_cumulative_yield = pool.cumulative_yield;
+
+    Ok(())
+}
+
+// LP tokens are freely transferable, the position follows its LP token account balance plus its escrowed locked LP.
+// Yield, rewards and boost are settled on the old balance first. LP that left the account is released to
+// pool.unsynced_lp, LP that arrived is only credited out of it, so a receiver syncing before its sender can't
+// earn on LP the sender is still credited with. Reward streams must be updated first
+pub fn sync_lp_balance(pool: &mut Pool, provider: &mut Provider, lp_token_balance: u64, now: i64) -> Result<(), SpiceError> {
+    settle_yield(pool, provider, now)?;
+    settle_rewards(pool, provider)?;
+    expire_lp_boost(pool, provider, now)?;
+
+    let synced_balance = provider.lp_balance.checked_sub(provider.locked_lp).ok_or(SpiceError::Overflow)?;
+    let credited_balance = if lp_token_balance < synced_balance {
+        pool.unsynced_lp = pool.unsynced_lp.checked_add(synced_balance - lp_token_balance).ok_or(SpiceError::Overflow)?;
+        lp_token_balance
+    } else {
+        let received = (lp_token_balance - synced_balance).min(pool.unsynced_lp);
+        pool.unsynced_lp -= received;
+        synced_balance + received
+    };
+    provider.lp_balance = credited_balance.checked_add(provider.locked_lp).ok_or(SpiceError::Overflow)?;
+
+    Ok(())
+}
+
+// Both sides of a plain LP transfer, the sender releases its LP before the receiver is credited
+pub fn sync_lp_transfer(
+    pool: &mut Pool,
+    sender: &mut Provider,
+    sender_lp_token_balance: u64,
+    receiver: &mut Provider,
+    receiver_lp_token_balance: u64,
+    now: i64,
+) -> Result<(), SpiceError> {
+    sync_lp_balance(pool, sender, sender_lp_token_balance, now)?;
+    sync_lp_balance(pool, receiver, receiver_lp_token_balance, now)
+}
+
+// Settles the position on its boosted balance up to now and drops a run out boost, returns the boost removed
+pub fn expire_position_boost(pool: &mut Pool, provider: &mut Provider, now: i64) -> Result<u64, SpiceError> {
+    if now < provider.lock_end {
+        return Err(SpiceError::LockNotExpired);
+    }
+
+    settle_yield(pool, provider, now)?;
+    settle_rewards(pool, provider)?;
+
+    let expired = provider.lp_boost;
+    expire_lp_boost(pool, provider, now)?;
+
+    Ok(expired)
+}
+
+// The deposit's LP is credited to the beneficiary's position, whoever funds it. Returns the LP to mint
+pub fn deposit_position(
+    pool: &mut Pool,
+    provider: &mut Provider,
+    beneficiary: Pubkey,
+    index: u8,
+    amount: u64,
+    lp_token_balance: u64,
+    now: i64,
+) -> Result<u64, SpiceError> {
+    sync_lp_balance(pool, provider, lp_token_balance, now)?;
+
+    // Covering first so the deposit is priced after the pool got its income back
+    cover_deficit(pool)?;
+    let lp_amount = deposit_liquidity(pool, amount)?;
+
+    provider.address = beneficiary;
+    provider.index = index;
+    provider.lp_balance = provider.lp_balance.checked_add(lp_amount).ok_or(SpiceError::Overflow)?;
+
+    Ok(lp_amount)
+}
+
+// Pending yield already sits in the treasury, compounding only re-labels it as pool liquidity
+pub fn compound_provider(pool: &mut Pool, provider: &mut Provider, now: i64) -> Result<u64, SpiceError> {
+    settle_yield(pool, provider, now)?;
+    settle_rewards(pool, provider)?;
+
+    cover_deficit(pool)?;
+    let lp_amount = deposit_liquidity(pool, provider.pending_claim)?;
+
+    provider.pending_claim = 0;
+    provider.lp_balance = provider.lp_balance.checked_add(lp_amount).ok_or(SpiceError::Overflow)?;
+
+    Ok(lp_amount)
+}
+
+pub fn check_provider_closable(provider: &Provider) -> Result<(), SpiceError> {
+    if provider.lp_balance > 0
+        || provider.locked_lp > 0
+        || provider.pending_claim > 0
+        || provider.reward_debts.iter().any(|debt| debt.pending > 0)
+    {
+        return Err(SpiceError::ProviderNotEmpty);
+    }
+
+    Ok(())
+}
+
+// Both positions must be synced first, the source is left empty
+pub fn merge_position(source: &mut Provider, destination: &mut Provider) -> Result<(), SpiceError> {
+    // The destination owner doesn't sign, a later lock can't be pushed onto its locked LP
+    if source.lock_end > destination.lock_end && destination.locked_lp > 0 {
+        return Err(SpiceError::LockExtended);
+    }
+
+    destination.lp_balance = destination.lp_balance.checked_add(source.lp_balance).ok_or(SpiceError::Overflow)?;
+    destination.pending_claim = destination.pending_claim.checked_add(source.pending_claim).ok_or(SpiceError::Overflow)?;
+
+    // The merged lock ends with the later of the two, each part keeps its boost
+    destination.locked_lp = destination.locked_lp.checked_add(source.locked_lp).ok_or(SpiceError::Overflow)?;
+    destination.lp_boost = destination.lp_boost.checked_add(source.lp_boost).ok_or(SpiceError::Overflow)?;
+    destination.lock_end = destination.lock_end.max(source.lock_end);
+
+    for (source_debt, destination_debt) in source.reward_debts.iter_mut().zip(destination.reward_debts.iter_mut()) {
+        destination_debt.pending = destination_debt.pending.checked_add(source_debt.pending).ok_or(SpiceError::Overflow)?;
+        source_debt.pending = 0;
+    }
+
+    source.lp_balance = 0;
+    source.pending_claim = 0;
+    source.locked_lp = 0;
+    source.lp_boost = 0;
+
+    Ok(())
+}
\ No newline at end of file
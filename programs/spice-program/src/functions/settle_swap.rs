@@ -0,0 +1,31 @@
+use crate::{errors::SpiceError, functions::{cover_deficit, distribute_fee, pay_rebate, SwapQuote}, states::{Pool, Settings}};
+
+pub fn settle_swap(
+    pool_a: &mut Pool,
+    pool_b: &mut Pool,
+    amount_in: u64,
+    quote: &SwapQuote,
+    settings: &Settings,
+    epoch: u64,
+) -> Result<(), SpiceError> {
+    distribute_fee(pool_a, quote.input_fee, settings)?;
+    distribute_fee(pool_b, quote.protocol_fee, settings)?;
+
+    // Protocol income is held apart from the liquidity providers can withdraw
+    pool_a.current_liquidity = pool_a.current_liquidity
+        .checked_add(amount_in - quote.input_fee)
+        .ok_or(SpiceError::Overflow)?;
+
+    pool_b.current_liquidity = pool_b.current_liquidity
+        .checked_sub(quote.raw_amount_out)
+        .ok_or(SpiceError::InsufficientLiquidity)?;
+
+    if quote.rebate > 0 {
+        pay_rebate(pool_b, quote.rebate, epoch)?;
+    }
+
+    cover_deficit(pool_a)?;
+    cover_deficit(pool_b)?;
+
+    Ok(())
+}
\ No newline at end of file
@@ -1,6 +1,6 @@
 use anchor_lang::{prelude::*, solana_program::system_instruction};
-use anchor_spl::token::{self, Token, TokenAccount};
-use crate::{constants::SOL, errors::SpiceError, states::Settings};
+use anchor_spl::{associated_token::get_associated_token_address, token::{self, Token, TokenAccount}};
+use crate::{constants::SOL, errors::SpiceError, states::{RewardStream, Settings}};
 
 pub fn transfer_to_treasury<'info>(
     signer: Signer<'info>,
@@ -44,8 +44,8 @@ pub fn transfer_to_treasury<'info>(
 
 
 pub fn transfer_from_treasury<'info>(
-    signer: Signer,
-    signer_ata: Option<Account<'info, TokenAccount>>, 
+    recipient: AccountInfo<'info>,
+    recipient_ata: Option<Account<'info, TokenAccount>>, 
     treasury: Account<'info, Settings>, 
     treasury_ata: Option<Account<'info, TokenAccount>>,
     token_mint: Pubkey, 
@@ -56,10 +56,10 @@ pub fn transfer_from_treasury<'info>(
 
     if token_mint.key().to_string() == SOL {
         **treasury.to_account_info().try_borrow_mut_lamports()? -= amount;
-        **signer.to_account_info().try_borrow_mut_lamports()? += amount;
+        **recipient.try_borrow_mut_lamports()? += amount;
     } else {
         let from_token_account = treasury_ata.as_ref().ok_or(SpiceError::MissingSPLAccount)?;
-        let to_token_account = signer_ata.as_ref().ok_or(SpiceError::MissingSPLAccount)?;
+        let to_token_account = recipient_ata.as_ref().ok_or(SpiceError::MissingSPLAccount)?;
 
         let cpi_accounts = token::Transfer {
             from: from_token_account.to_account_info(),
@@ -104,5 +104,41 @@ pub fn transfer_partner_fee<'info>(
         )?;
     }
 
+    Ok(())
+}
+
+// Undistributed rewards go from the Treasury escrow to the funder's ATA of the reward mint
+pub fn refund_reward_funder<'info>(
+    stream: &RewardStream,
+    funder_reward_ata: Option<Account<'info, TokenAccount>>,
+    treasury: Account<'info, Settings>,
+    treasury_reward_ata: Option<Account<'info, TokenAccount>>,
+    amount: u64,
+    token_program: Program<'info, Token>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let from_token_account = treasury_reward_ata.as_ref().ok_or(SpiceError::MissingSPLAccount)?;
+    let to_token_account = funder_reward_ata.as_ref().ok_or(SpiceError::MissingSPLAccount)?;
+
+    if from_token_account.key() != get_associated_token_address(&treasury.key(), &stream.mint)
+        || to_token_account.key() != get_associated_token_address(&stream.funder, &stream.mint)
+    {
+        return Err(SpiceError::InvalidRewardStream.into());
+    }
+
+    let cpi_accounts = token::Transfer {
+        from: from_token_account.to_account_info(),
+        to: to_token_account.to_account_info(),
+        authority: treasury.to_account_info(),
+    };
+    token::transfer(
+        CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, signer_seeds),
+        amount,
+    )?;
+
     Ok(())
 }
\ No newline at end of file
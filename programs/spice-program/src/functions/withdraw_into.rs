@@ -0,0 +1,31 @@
+use crate::{errors::SpiceError, functions::{cover_deficit, quote_swap, settle_swap, withdraw_with_fee, SwapQuote, WithdrawalQuote}, states::{Pool, Settings}};
+
+// The withdrawn pool_a tokens never leave the treasury, they are swapped into pool_b at oracle prices
+pub fn withdraw_into(
+    pool_a: &mut Pool,
+    pool_b: &mut Pool,
+    lp_amount: u64,
+    prices: (u64, u64),
+    decimals: (u8, u8),
+    settings: &Settings,
+    epoch: u64,
+) -> Result<(WithdrawalQuote, SwapQuote), SpiceError> {
+    // Same withdrawal fee as decrease_liquidity, only the net amount is swapped
+    cover_deficit(pool_a)?;
+    let withdrawal = withdraw_with_fee(pool_a, lp_amount)?;
+    let withdrawal_amount = withdrawal.amount;
+
+    let mut quote = quote_swap(pool_a, pool_b, withdrawal_amount, prices, decimals, 0, epoch)?;
+
+    // Pool A's liquidity did not improve, the withdrawal only returns to it, so no rebalancing rebate
+    quote.amount_out -= quote.rebate;
+    quote.rebate = 0;
+
+    if quote.amount_out > pool_b.current_liquidity {
+        return Err(SpiceError::InsufficientLiquidity);
+    }
+
+    settle_swap(pool_a, pool_b, withdrawal_amount, &quote, settings, epoch)?;
+
+    Ok((withdrawal, quote))
+}
\ No newline at end of file
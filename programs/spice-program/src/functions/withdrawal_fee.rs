@@ -0,0 +1,61 @@
+use anchor_lang::prelude::{borsh, AnchorDeserialize, AnchorSerialize};
+
+use crate::{constants::{FEE_SCALE, SPICE_SCALE}, errors::SpiceError, functions::{pool_value, withdraw_liquidity}, states::Pool};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct WithdrawalQuote {
+    pub amount: u64,
+    pub fee: u64,
+}
+
+// Grows with the pool's deficit depth, larger withdrawals pay proportionally more
+pub fn withdrawal_fee_rate(pool: &Pool, amount: u64) -> Result<u64, SpiceError> {
+    let value = u128::from(pool_value(pool)?);
+    let initial_liquidity = u128::from(pool.initial_liquidity);
+
+    if value >= initial_liquidity || value == 0 {
+        return Ok(0);
+    }
+
+    let depth = (initial_liquidity - value) * FEE_SCALE / initial_liquidity;
+    let size = u128::from(amount).min(value) * FEE_SCALE / value;
+    let params = &pool.withdrawal_fee;
+
+    let rate = depth
+        .checked_mul(u128::from(params.depth_steepness))
+        .and_then(|num| num.checked_mul(FEE_SCALE + size * u128::from(params.size_steepness) / u128::from(SPICE_SCALE)))
+        .map(|num| num / (u128::from(SPICE_SCALE) * FEE_SCALE))
+        .ok_or(SpiceError::Overflow)?;
+
+    Ok(rate.min(u128::from(params.max_fee)) as u64)
+}
+
+// The last provider out pays no fee, there is nobody left to credit it to
+pub fn quote_withdrawal(pool: &Pool, lp_amount: u64) -> Result<WithdrawalQuote, SpiceError> {
+    if lp_amount > pool.lp_supply {
+        return Err(SpiceError::InvalidLpAmount);
+    }
+
+    let amount = (u128::from(lp_amount) * u128::from(pool_value(pool)?) / u128::from(pool.lp_supply)) as u64;
+    if lp_amount == pool.lp_supply {
+        return Ok(WithdrawalQuote { amount, fee: 0 });
+    }
+
+    let rate = withdrawal_fee_rate(pool, amount)?;
+    let fee = (u128::from(amount) * u128::from(rate) / FEE_SCALE) as u64;
+
+    Ok(WithdrawalQuote { amount: amount - fee, fee })
+}
+
+// The fee stays in the treasury as yield for the remaining providers
+pub fn withdraw_with_fee(pool: &mut Pool, lp_amount: u64) -> Result<WithdrawalQuote, SpiceError> {
+    let quote = quote_withdrawal(pool, lp_amount)?;
+    withdraw_liquidity(pool, lp_amount)?;
+
+    pool.cumulative_yield = quote.fee
+        .checked_mul(SPICE_SCALE)
+        .and_then(|fee| pool.cumulative_yield.checked_add(fee))
+        .ok_or(SpiceError::Overflow)?;
+
+    Ok(quote)
+}
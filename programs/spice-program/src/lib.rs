@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
 use public_instructions::*;
 use admin_instructions::*;
+use functions::WithdrawalQuote;
+use states::{DynamicFeeParams, FeeCurve, FeeRecipient, WithdrawalFeeParams};
 
 pub mod states;
 pub mod public_instructions;
@@ -15,42 +17,174 @@ declare_id!("CcoYv1X4RczUmuGCUw3Vg1XWxspaofsh8dtD26cmN7nm");
 pub mod spice_program {
     use super::*;
 
-    pub fn treasury_settings(ctx: Context<TreasurySettingsInstructionAccounts>, income_distribution: u64, stoptap: bool) -> Result<()> {
-        admin_instructions::treasury_settings(ctx, income_distribution, stoptap)
+    pub fn treasury_settings(ctx: Context<TreasurySettingsInstructionAccounts>, protocol_share: u64, stoptap: bool) -> Result<()> {
+        admin_instructions::treasury_settings(ctx, protocol_share, stoptap)
     }
 
-    pub fn update_settings(ctx: Context<UpdateSettingsInstructionAccounts>, income_distribution: u64, stoptap: bool) -> Result<()> {
-        admin_instructions::update_settings(ctx, income_distribution, stoptap)
+    pub fn update_settings(ctx: Context<UpdateSettingsInstructionAccounts>, protocol_share: u64, stoptap: bool) -> Result<()> {
+        admin_instructions::update_settings(ctx, protocol_share, stoptap)
     }
 
     pub fn create_pool(ctx: Context<InitPoolInstructionAccounts>, is_active: bool, base_fee: u64) -> Result<()> {
         admin_instructions::create_pool(ctx, is_active, base_fee)
     }
 
-    pub fn set_pool_settings(ctx: Context<SetPoolSettingsInstructionAccounts>, is_active: bool, base_fee: u64) -> Result<()> {
-        admin_instructions::set_pool_settings(ctx, is_active, base_fee)
+    pub fn set_pool_settings(ctx: Context<SetPoolSettingsInstructionAccounts>, is_active: bool, base_fee: u64, flash_loan_fee: u64, imbalance_fee: DynamicFeeParams, regular_fee: DynamicFeeParams) -> Result<()> {
+        admin_instructions::set_pool_settings(ctx, is_active, base_fee, flash_loan_fee, imbalance_fee, regular_fee)
     }
     
-    pub fn collect_protocol_income(ctx: Context<CollectProtocolIncomeInstructionAccounts>) -> Result<()> {
-        admin_instructions::collect_protocol_income(ctx)
+    pub fn collect_protocol_income(ctx: Context<CollectProtocolIncomeInstructionAccounts>, amount: Option<u64>) -> Result<()> {
+        admin_instructions::collect_protocol_income(ctx, amount)
+    }
+
+    pub fn set_fee_curve(ctx: Context<SetFeeCurveInstructionAccounts>, fee_curve: FeeCurve) -> Result<()> {
+        admin_instructions::set_fee_curve(ctx, fee_curve)
+    }
+
+    pub fn set_rebate_settings(ctx: Context<SetRebateSettingsInstructionAccounts>, rebate_rate: u64, max_rebate_per_trade: u64, max_rebate_per_epoch: u64) -> Result<()> {
+        admin_instructions::set_rebate_settings(ctx, rebate_rate, max_rebate_per_trade, max_rebate_per_epoch)
+    }
+
+    pub fn add_reward_stream(ctx: Context<AddRewardStreamInstructionAccounts>, slot: u8, rate: u64, start: i64, end: i64) -> Result<()> {
+        admin_instructions::add_reward_stream(ctx, slot, rate, start, end)
+    }
+
+    pub fn create_lp_metadata(ctx: Context<CreateLpMetadataInstructionAccounts>, uri: String) -> Result<()> {
+        admin_instructions::create_lp_metadata(ctx, uri)
+    }
+
+    pub fn update_lp_metadata_uri(ctx: Context<UpdateLpMetadataUriInstructionAccounts>, uri: String) -> Result<()> {
+        admin_instructions::update_lp_metadata_uri(ctx, uri)
+    }
+
+    pub fn set_deficit_coverage(ctx: Context<SetDeficitCoverageInstructionAccounts>, deficit_coverage: u64) -> Result<()> {
+        admin_instructions::set_deficit_coverage(ctx, deficit_coverage)
+    }
+
+    pub fn set_fee_split(ctx: Context<SetFeeSplitInstructionAccounts>, recipients: Vec<FeeRecipient>) -> Result<()> {
+        admin_instructions::set_fee_split(ctx, recipients)
+    }
+
+    pub fn set_pool_protocol_share(ctx: Context<SetPoolProtocolShareInstructionAccounts>, protocol_share: Option<u64>) -> Result<()> {
+        admin_instructions::set_pool_protocol_share(ctx, protocol_share)
+    }
+
+    pub fn migrate_settings(ctx: Context<MigrateSettingsInstructionAccounts>) -> Result<()> {
+        admin_instructions::migrate_settings(ctx)
+    }
+
+    pub fn set_withdrawal_fee(ctx: Context<SetWithdrawalFeeInstructionAccounts>, withdrawal_fee: WithdrawalFeeParams) -> Result<()> {
+        admin_instructions::set_withdrawal_fee(ctx, withdrawal_fee)
+    }
+
+    pub fn migrate_pool(ctx: Context<MigratePoolInstructionAccounts>, collected_income: u64) -> Result<()> {
+        admin_instructions::migrate_pool(ctx, collected_income)
     }
 
     //---------
 
-    pub fn increase_liquidity(ctx: Context<IncreaseLiquidityInstructionAccounts>, amount: u64) -> Result<()> {
-        public_instructions::increase_liquidity(ctx, amount)
+    pub fn increase_liquidity(ctx: Context<IncreaseLiquidityInstructionAccounts>, index: u8, amount: u64) -> Result<()> {
+        public_instructions::increase_liquidity(ctx, index, amount)
+    }
+
+    pub fn decrease_liquidity(ctx: Context<DecreaseLiquidityInstructionAccounts>, index: u8, amount: u64, close_provider: bool) -> Result<()> {
+        public_instructions::decrease_liquidity(ctx, index, amount, close_provider)
     }
 
-    pub fn decrease_liquidity(ctx: Context<DecreaseLiquidityInstructionAccounts>, amount: u64) -> Result<()> {
-        public_instructions::decrease_liquidity(ctx, amount)
+    pub fn decrease_liquidity_into(ctx: Context<DecreaseLiquidityIntoInstructionAccounts>, index: u8, amount: u64, min_amount_out: u64) -> Result<()> {
+        public_instructions::decrease_liquidity_into(ctx, index, amount, min_amount_out)
     }
 
-    pub fn harvest_yield(ctx: Context<HarvestYieldInstructionAccounts>) -> Result<()> {
-        public_instructions::harvest_yield(ctx)
+    pub fn harvest_yield(ctx: Context<HarvestYieldInstructionAccounts>, index: u8) -> Result<()> {
+        public_instructions::harvest_yield(ctx, index)
     }
 
     pub fn swap(ctx: Context<SwapInstructionAccounts>, input: u64, output: u64, fee_bps: u64) -> Result<()> {
         public_instructions::swap(ctx, input, output, fee_bps)
     }
+
+    pub fn create_dca_order(ctx: Context<CreateDcaOrderInstructionAccounts>, id: u64, amount: u64, slices: u64, min_interval: i64, min_amount_out: u64) -> Result<()> {
+        public_instructions::create_dca_order(ctx, id, amount, slices, min_interval, min_amount_out)
+    }
+
+    pub fn execute_dca_order(ctx: Context<ExecuteDcaOrderInstructionAccounts>) -> Result<()> {
+        public_instructions::execute_dca_order(ctx)
+    }
+
+    pub fn close_dca_order(ctx: Context<CloseDcaOrderInstructionAccounts>) -> Result<()> {
+        public_instructions::close_dca_order(ctx)
+    }
+
+    pub fn flash_borrow(ctx: Context<FlashBorrowInstructionAccounts>, amount: u64) -> Result<()> {
+        public_instructions::flash_borrow(ctx, amount)
+    }
+
+    pub fn flash_repay(ctx: Context<FlashRepayInstructionAccounts>) -> Result<()> {
+        public_instructions::flash_repay(ctx)
+    }
+
+    pub fn sync_provider(ctx: Context<SyncProviderInstructionAccounts>, index: u8, receiver_index: u8) -> Result<()> {
+        public_instructions::sync_provider(ctx, index, receiver_index)
+    }
+
+    pub fn compound_yield(ctx: Context<CompoundYieldInstructionAccounts>, index: u8) -> Result<()> {
+        public_instructions::compound_yield(ctx, index)
+    }
+
+    pub fn set_keeper_settings(ctx: Context<SetKeeperSettingsInstructionAccounts>, index: u8, keeper_enabled: bool, keeper_tip: u64) -> Result<()> {
+        public_instructions::set_keeper_settings(ctx, index, keeper_enabled, keeper_tip)
+    }
+
+    pub fn keeper_compound(ctx: Context<KeeperCompoundInstructionAccounts>, index: u8) -> Result<()> {
+        public_instructions::keeper_compound(ctx, index)
+    }
+
+    pub fn keeper_harvest(ctx: Context<KeeperHarvestInstructionAccounts>, index: u8) -> Result<()> {
+        public_instructions::keeper_harvest(ctx, index)
+    }
+
+    pub fn lock_liquidity(ctx: Context<LockLiquidityInstructionAccounts>, index: u8, amount: u64, lock_duration: i64) -> Result<()> {
+        public_instructions::lock_liquidity(ctx, index, amount, lock_duration)
+    }
+
+    pub fn unlock_liquidity(ctx: Context<UnlockLiquidityInstructionAccounts>, index: u8) -> Result<()> {
+        public_instructions::unlock_liquidity(ctx, index)
+    }
+
+    pub fn claim_rewards(ctx: Context<ClaimRewardsInstructionAccounts>, index: u8, slot: u8) -> Result<()> {
+        public_instructions::claim_rewards(ctx, index, slot)
+    }
+
+    pub fn fund_incentive(ctx: Context<FundIncentiveInstructionAccounts>, rate: u64, start: i64, end: i64) -> Result<()> {
+        public_instructions::fund_incentive(ctx, rate, start, end)
+    }
+
+    pub fn refund_incentive(ctx: Context<RefundIncentiveInstructionAccounts>, slot: u8) -> Result<()> {
+        public_instructions::refund_incentive(ctx, slot)
+    }
+
+    pub fn close_provider(ctx: Context<CloseProviderInstructionAccounts>, index: u8) -> Result<()> {
+        public_instructions::close_provider(ctx, index)
+    }
+
+    pub fn transfer_position(ctx: Context<TransferPositionInstructionAccounts>, index: u8, destination_index: u8) -> Result<()> {
+        public_instructions::transfer_position(ctx, index, destination_index)
+    }
+
+    pub fn claim_protocol_fees(ctx: Context<ClaimProtocolFeesInstructionAccounts>) -> Result<()> {
+        public_instructions::claim_protocol_fees(ctx)
+    }
+
+    pub fn quote_withdrawal(ctx: Context<QuoteWithdrawalInstructionAccounts>, amount: u64) -> Result<WithdrawalQuote> {
+        public_instructions::quote_withdrawal(ctx, amount)
+    }
+
+    pub fn migrate_provider(ctx: Context<MigrateProviderInstructionAccounts>, index: u8) -> Result<()> {
+        public_instructions::migrate_provider(ctx, index)
+    }
+
+    pub fn expire_boost(ctx: Context<ExpireBoostInstructionAccounts>, index: u8) -> Result<()> {
+        public_instructions::expire_boost(ctx, index)
+    }
     
 }
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::{constants::{POOL_SEED, SPICE_SEED, TREASURY_SEED}, functions::{take_fee_share, transfer_from_treasury}, states::{Pool, Settings}};
+
+pub fn claim_protocol_fees(ctx: Context<ClaimProtocolFeesInstructionAccounts>) -> Result<()> {
+    let amount = take_fee_share(&mut ctx.accounts.pool_pda, ctx.accounts.signer.key())?;
+    msg!("Protocol fees: {}", amount);
+
+    // Transfer from Treasury to Recipient
+    let seeds = &[SPICE_SEED.as_bytes(), TREASURY_SEED.as_bytes(), &[ctx.bumps.treasury]];
+    let signer = [&seeds[..]];
+
+    transfer_from_treasury(
+        ctx.accounts.signer.to_account_info(), 
+        ctx.accounts.signer_ata.clone(), 
+        ctx.accounts.treasury.clone(), 
+        ctx.accounts.treasury_ata.clone(), 
+        ctx.accounts.mint.key(), 
+        amount, 
+        ctx.accounts.token_program.clone(), 
+        &signer)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimProtocolFeesInstructionAccounts<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// CHECK:
+    pub mint: AccountInfo<'info>,
+
+    #[account(mut, token::authority = signer, token::mint = mint)]
+    pub signer_ata: Option<Account<'info, TokenAccount>>,
+
+    #[account(mut, seeds = [POOL_SEED.as_bytes(), mint.key().as_ref()], bump)]
+    pub pool_pda: Account<'info, Pool>,
+
+    #[account(mut, seeds = [SPICE_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury: Account<'info, Settings>,
+
+    #[account(mut, token::authority = treasury, token::mint = mint)]
+    pub treasury_ata: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
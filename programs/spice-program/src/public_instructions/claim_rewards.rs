@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token::{self, Mint, Token, TokenAccount}};
+
+use crate::{constants::{MINT_SEED, POOL_SEED, POSITION_SEED, PROVIDER_SEED, SPICE_SEED, TREASURY_SEED}, functions::{check_stoptap, sync_lp_balance, take_rewards, update_reward_streams}, states::{Pool, Provider, Settings}};
+
+pub fn claim_rewards(ctx: Context<ClaimRewardsInstructionAccounts>, index: u8, slot: u8) -> Result<()> {
+    check_stoptap(&ctx.accounts.treasury)?;
+    msg!("Position: {}", index);
+
+    let now = Clock::get()?.unix_timestamp;
+    update_reward_streams(&mut ctx.accounts.pool_pda, now)?;
+    sync_lp_balance(&mut ctx.accounts.pool_pda, &mut ctx.accounts.provider, ctx.accounts.position_lp_account.amount, now)?;
+
+    let rewards = take_rewards(&ctx.accounts.pool_pda, &mut ctx.accounts.provider, slot as usize)?;
+    msg!("Rewards: {}", rewards);
+
+    // Transfer from Treasury to User
+    let seeds = &[SPICE_SEED.as_bytes(), TREASURY_SEED.as_bytes(), &[ctx.bumps.treasury]];
+    let signer = [&seeds[..]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.treasury_reward_ata.to_account_info(),
+                to: ctx.accounts.signer_reward_ata.to_account_info(),
+                authority: ctx.accounts.treasury.to_account_info(),
+            },
+            &signer,
+        ),
+        rewards,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(index: u8, slot: u8)]
+pub struct ClaimRewardsInstructionAccounts<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// CHECK:
+    pub mint: AccountInfo<'info>,
+
+    #[account(mut, seeds = [POOL_SEED.as_bytes(), mint.key().as_ref()], bump)]
+    pub pool_pda: Account<'info, Pool>,
+
+    #[account(seeds = [MINT_SEED.as_bytes(), pool_pda.key().as_ref()], bump)]
+    pub lp_token_mint_pda: Account<'info, Mint>,
+
+    #[account(mut, seeds = [PROVIDER_SEED.as_bytes(), pool_pda.key().as_ref(), signer.key().as_ref(), &[index]], bump)]
+    pub provider: Account<'info, Provider>,
+
+    #[account(seeds = [POSITION_SEED.as_bytes(), provider.key().as_ref()], bump, token::mint = lp_token_mint_pda, token::authority = signer)]
+    pub position_lp_account: Account<'info, TokenAccount>,
+
+    #[account(constraint = pool_pda.reward_streams.get(slot as usize).is_some_and(|stream| stream.mint == reward_mint.key()))]
+    pub reward_mint: Account<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = reward_mint,
+        associated_token::authority = signer,
+    )]
+    pub signer_reward_ata: Account<'info, TokenAccount>,
+
+    #[account(seeds = [SPICE_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury: Account<'info, Settings>,
+
+    #[account(mut, associated_token::mint = reward_mint, associated_token::authority = treasury)]
+    pub treasury_reward_ata: Account<'info, TokenAccount>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
\ No newline at end of file
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::{constants::{DCA_SEED, SPICE_SEED, TREASURY_SEED}, functions::{cancel_dca_order, transfer_from_treasury}, states::{DcaOrder, Settings}};
+
+pub fn close_dca_order(ctx: Context<CloseDcaOrderInstructionAccounts>) -> Result<()> {
+    let remaining_amount = cancel_dca_order(&mut ctx.accounts.dca_order);
+    msg!("Refund: {}", remaining_amount);
+
+    // Transfer from Treasury to Owner
+    let seeds = &[SPICE_SEED.as_bytes(), TREASURY_SEED.as_bytes(), &[ctx.bumps.treasury]];
+    let signer = [&seeds[..]];
+
+    transfer_from_treasury(
+        ctx.accounts.signer.to_account_info(), 
+        ctx.accounts.signer_ata.clone(), 
+        ctx.accounts.treasury.clone(), 
+        ctx.accounts.treasury_ata.clone(), 
+        ctx.accounts.input_mint.key(), 
+        remaining_amount, 
+        ctx.accounts.token_program.clone(), 
+        &signer)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseDcaOrderInstructionAccounts<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// CHECK: dca_order.input_mint
+    #[account(address = dca_order.input_mint)]
+    pub input_mint: AccountInfo<'info>,
+
+    #[account(mut, token::authority = signer, token::mint = input_mint)]
+    pub signer_ata: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        close = signer,
+        seeds = [DCA_SEED.as_bytes(), signer.key().as_ref(), &dca_order.id.to_le_bytes()],
+        bump
+    )]
+    pub dca_order: Account<'info, DcaOrder>,
+
+    /// CHECK:
+    #[account(mut, seeds = [SPICE_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury: Account<'info, Settings>,
+
+    #[account(mut, token::authority = treasury, token::mint = input_mint)]
+    pub treasury_ata: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
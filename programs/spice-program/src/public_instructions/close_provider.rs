@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token::{self, Mint, Token, TokenAccount}};
+
+use crate::{constants::{MINT_SEED, POOL_SEED, POSITION_REGISTRY_SEED, POSITION_SEED, PROVIDER_SEED}, functions::{check_provider_closable, sync_lp_balance, unregister_position, update_reward_streams}, states::{Pool, PositionRegistry, Provider}};
+
+pub fn close_provider(ctx: Context<CloseProviderInstructionAccounts>, index: u8) -> Result<()> {
+    msg!("Position: {}", index);
+
+    let now = Clock::get()?.unix_timestamp;
+    update_reward_streams(&mut ctx.accounts.pool_pda, now)?;
+    sync_lp_balance(&mut ctx.accounts.pool_pda, &mut ctx.accounts.provider, ctx.accounts.position_lp_account.amount, now)?;
+
+    check_provider_closable(&ctx.accounts.provider)?;
+    unregister_position(&mut ctx.accounts.position_registry, index)?;
+
+    token::close_account(CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        token::CloseAccount {
+            account: ctx.accounts.position_lp_account.to_account_info(),
+            destination: ctx.accounts.signer.to_account_info(),
+            authority: ctx.accounts.signer.to_account_info(),
+        },
+    ))?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(index: u8)]
+pub struct CloseProviderInstructionAccounts<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// CHECK:
+    pub mint: AccountInfo<'info>,
+
+    #[account(mut, seeds = [POOL_SEED.as_bytes(), mint.key().as_ref()], bump)]
+    pub pool_pda: Account<'info, Pool>,
+
+    #[account(seeds = [MINT_SEED.as_bytes(), pool_pda.key().as_ref()], bump)]
+    pub lp_token_mint_pda: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        close = signer,
+        seeds = [PROVIDER_SEED.as_bytes(), pool_pda.key().as_ref(), signer.key().as_ref(), &[index]],
+        bump,
+    )]
+    pub provider: Account<'info, Provider>,
+
+    #[account(mut, seeds = [POSITION_SEED.as_bytes(), provider.key().as_ref()], bump, token::mint = lp_token_mint_pda, token::authority = signer)]
+    pub position_lp_account: Account<'info, TokenAccount>,
+
+    #[account(mut, seeds = [POSITION_REGISTRY_SEED.as_bytes(), pool_pda.key().as_ref(), signer.key().as_ref()], bump)]
+    pub position_registry: Account<'info, PositionRegistry>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
\ No newline at end of file
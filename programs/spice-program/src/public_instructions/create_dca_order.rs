@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::{
+    constants::{DCA_SEED, POOL_SEED, SPICE_SEED, TREASURY_SEED}, errors::SpiceError, functions::{check_dca_order, check_stoptap, transfer_to_treasury}, states::{DcaOrder, Pool, Settings}
+};
+
+pub fn create_dca_order(
+    ctx: Context<CreateDcaOrderInstructionAccounts>,
+    id: u64,
+    amount: u64,
+    slices: u64,
+    min_interval: i64,
+    min_amount_out: u64,
+) -> Result<()> {
+    check_stoptap(&ctx.accounts.treasury)?;
+
+    if ctx.accounts.input_mint.key() == ctx.accounts.output_mint.key() {
+        return Err(SpiceError::SameMint.into());
+    }
+    let amount_per_slice = check_dca_order(amount, slices, min_interval)?;
+
+    transfer_to_treasury(
+        ctx.accounts.signer.clone(), 
+        ctx.accounts.signer_ata.clone(), 
+        ctx.accounts.treasury.clone(), 
+        ctx.accounts.treasury_ata.clone(), 
+        ctx.accounts.input_mint.key(), 
+        amount, 
+        ctx.accounts.token_program.clone(), 
+        ctx.accounts.system_program.clone())?;
+
+    ctx.accounts.dca_order.owner = ctx.accounts.signer.key();
+    ctx.accounts.dca_order.id = id;
+    ctx.accounts.dca_order.input_mint = ctx.accounts.input_mint.key();
+    ctx.accounts.dca_order.output_mint = ctx.accounts.output_mint.key();
+    ctx.accounts.dca_order.amount_per_slice = amount_per_slice;
+    ctx.accounts.dca_order.remaining_amount = amount;
+    ctx.accounts.dca_order.slices = slices;
+    ctx.accounts.dca_order.executed_slices = 0;
+    ctx.accounts.dca_order.min_interval = min_interval;
+    ctx.accounts.dca_order.last_execution = 0;
+    ctx.accounts.dca_order.min_amount_out = min_amount_out;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct CreateDcaOrderInstructionAccounts<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// CHECK:
+    pub input_mint: AccountInfo<'info>,
+
+    /// CHECK:
+    pub output_mint: AccountInfo<'info>,
+
+    #[account(seeds = [POOL_SEED.as_bytes(), input_mint.key().as_ref()], bump)]
+    pub a_pool_pda: Account<'info, Pool>,
+
+    #[account(seeds = [POOL_SEED.as_bytes(), output_mint.key().as_ref()], bump)]
+    pub b_pool_pda: Account<'info, Pool>,
+
+    #[account(mut, token::authority = signer, token::mint = input_mint)]
+    pub signer_ata: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = signer,
+        seeds = [DCA_SEED.as_bytes(), signer.key().as_ref(), &id.to_le_bytes()],
+        bump,
+        space = 8 + 32 + 8 + 32 + 32 + 8 + 8 + 8 + 8 + 8 + 8 + 8,
+    )]
+    pub dca_order: Account<'info, DcaOrder>,
+
+    #[account(mut, seeds = [SPICE_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury: Account<'info, Settings>,
+
+    #[account(mut, token::authority = treasury, token::mint = input_mint)]
+    pub treasury_ata: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
@@ -1,35 +1,39 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{associated_token::AssociatedToken, token::{self, Mint, Token, TokenAccount}};
 
-use crate::{constants::{MINT_SEED, POOL_SEED, PROVIDER_SEED, SPICE_SEED, TREASURY_SEED}, errors::SpiceError, functions::{check_stoptap, calculate_yield, transfer_from_treasury}, states::{Pool, Provider, Settings}};
+use crate::{constants::{MINT_SEED, POOL_SEED, POSITION_REGISTRY_SEED, POSITION_SEED, PROVIDER_SEED, SPICE_SEED, TREASURY_SEED}, errors::SpiceError, functions::{check_provider_closable, check_stoptap, cover_deficit, sync_lp_balance, transfer_from_treasury, unregister_position, update_reward_streams, withdraw_with_fee}, states::{Pool, PositionRegistry, Provider, Settings}};
 
-pub fn decrease_liquidity(ctx: Context<DecreaseLiquidityInstructionAccounts>, amount: u64) -> Result<()> {
+pub fn decrease_liquidity(ctx: Context<DecreaseLiquidityInstructionAccounts>, index: u8, amount: u64, close_provider: bool) -> Result<()> {
     check_stoptap(&ctx.accounts.treasury)?;
+    msg!("Position: {}", index);
     
-    let provider_lp_balance: u64 = ctx.accounts.provider.lp_balance;
-    if provider_lp_balance < amount {
+    let now = Clock::get()?.unix_timestamp;
+    update_reward_streams(&mut ctx.accounts.pool_pda, now)?;
+    sync_lp_balance(&mut ctx.accounts.pool_pda, &mut ctx.accounts.provider, ctx.accounts.position_lp_account.amount, now)?;
+
+    if ctx.accounts.provider.lp_balance < amount {
         return err!(SpiceError::InvalidLpAmount);
     }
 
-    let total_lp = ctx.accounts.pool_pda.initial_liquidity;
-    let provider_income: u64 = calculate_yield(ctx.accounts.pool_pda.cumulative_yield, total_lp, provider_lp_balance, ctx.accounts.provider.last_cumulative_yield)? + ctx.accounts.provider.pending_claim;
-    
-    let pool_delta = ctx.accounts.pool_pda.current_liquidity as i64 - ctx.accounts.pool_pda.initial_liquidity as i64;
-    let mut total_withdrawal_amount: u64 = amount + provider_income;
-
-    if pool_delta < 0 {
-        let fee = total_withdrawal_amount / 100;
-        total_withdrawal_amount -= fee;
+    // Locked LP is escrowed until the lock expires
+    if ctx.accounts.provider.lp_balance - ctx.accounts.provider.locked_lp < amount {
+        return err!(SpiceError::LiquidityLocked);
     }
 
+    let provider_income: u64 = ctx.accounts.provider.pending_claim;
+
+    cover_deficit(&mut ctx.accounts.pool_pda)?;
+
+    // Update Pool
+    let withdrawal = withdraw_with_fee(&mut ctx.accounts.pool_pda, amount)?;
+    let total_withdrawal_amount: u64 = withdrawal.amount + provider_income;
+
+    msg!("Withdrawal: {}", withdrawal.amount);
+    msg!("Withdrawal fee: {}", withdrawal.fee);
+
     // Update Provider
     ctx.accounts.provider.lp_balance -= amount;
-    ctx.accounts.provider.last_cumulative_yield = ctx.accounts.pool_pda.cumulative_yield;
     ctx.accounts.provider.pending_claim = 0;
-    
-    // Update Pool
-    ctx.accounts.pool_pda.initial_liquidity -= amount;
-    ctx.accounts.pool_pda.current_liquidity -= amount;
 
     // Burn LP
     token::burn(
@@ -37,7 +41,7 @@ pub fn decrease_liquidity(ctx: Context<DecreaseLiquidityInstructionAccounts>, am
             ctx.accounts.token_program.to_account_info(),
             token::Burn {
                 mint: ctx.accounts.lp_token_mint_pda.to_account_info(),
-                from: ctx.accounts.signer_lp_ata.to_account_info(),
+                from: ctx.accounts.position_lp_account.to_account_info(),
                 authority: ctx.accounts.signer.to_account_info(),
             },
         ),
@@ -49,7 +53,7 @@ pub fn decrease_liquidity(ctx: Context<DecreaseLiquidityInstructionAccounts>, am
     let signer = [&seeds[..]];
 
     transfer_from_treasury(
-        ctx.accounts.signer.clone(), 
+        ctx.accounts.signer.to_account_info(), 
         ctx.accounts.signer_ata.clone(), 
         ctx.accounts.treasury.clone(), 
         ctx.accounts.treasury_ata.clone(), 
@@ -58,11 +62,28 @@ pub fn decrease_liquidity(ctx: Context<DecreaseLiquidityInstructionAccounts>, am
         ctx.accounts.token_program.clone(), 
         &signer)?;
 
+    // Close Provider after a full exit
+    if close_provider {
+        check_provider_closable(&ctx.accounts.provider)?;
+        unregister_position(&mut ctx.accounts.position_registry, index)?;
+
+        token::close_account(CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token::CloseAccount {
+                account: ctx.accounts.position_lp_account.to_account_info(),
+                destination: ctx.accounts.signer.to_account_info(),
+                authority: ctx.accounts.signer.to_account_info(),
+            },
+        ))?;
+        ctx.accounts.provider.close(ctx.accounts.signer.to_account_info())?;
+    }
+
     Ok(())
 }
 
 
 #[derive(Accounts)]
+#[instruction(index: u8)]
 pub struct DecreaseLiquidityInstructionAccounts<'info> {
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -73,18 +94,21 @@ pub struct DecreaseLiquidityInstructionAccounts<'info> {
     #[account(mut, token::authority = signer, token::mint = mint)]
     pub signer_ata: Option<Account<'info, TokenAccount>>,
 
-    #[account(mut, token::authority = signer, token::mint = lp_token_mint_pda)]
-    pub signer_lp_ata: Account<'info, TokenAccount>,
-
     #[account(mut, seeds = [POOL_SEED.as_bytes(), mint.key().as_ref()], bump)]
     pub pool_pda: Account<'info, Pool>,
 
     #[account(mut, seeds = [MINT_SEED.as_bytes(), pool_pda.key().as_ref()], bump)]
     pub lp_token_mint_pda: Account<'info, Mint>,
 
-    #[account(mut, seeds = [PROVIDER_SEED.as_bytes(), pool_pda.key().as_ref(), signer.key().as_ref()], bump)]
+    #[account(mut, seeds = [PROVIDER_SEED.as_bytes(), pool_pda.key().as_ref(), signer.key().as_ref(), &[index]], bump)]
     pub provider: Account<'info, Provider>,
 
+    #[account(mut, seeds = [POSITION_SEED.as_bytes(), provider.key().as_ref()], bump, token::mint = lp_token_mint_pda, token::authority = signer)]
+    pub position_lp_account: Account<'info, TokenAccount>,
+
+    #[account(mut, seeds = [POSITION_REGISTRY_SEED.as_bytes(), pool_pda.key().as_ref(), signer.key().as_ref()], bump)]
+    pub position_registry: Account<'info, PositionRegistry>,
+
     /// CHECK:
     #[account(mut, seeds = [SPICE_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
     pub treasury: Account<'info, Settings>,
@@ -0,0 +1,143 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+
+use crate::{
+    constants::{MINT_SEED, POOL_SEED, POSITION_SEED, PROVIDER_SEED, SPICE_SEED, TREASURY_SEED},
+    errors::SpiceError,
+    functions::{check_pools, check_stoptap, sync_lp_balance, transfer_from_treasury, update_reward_streams, withdraw_into},
+    states::{Pool, Provider, Settings},
+};
+
+// Redeems LP in another pool's token, pending yield stays claimable
+pub fn decrease_liquidity_into(
+    ctx: Context<DecreaseLiquidityIntoInstructionAccounts>,
+    index: u8,
+    amount: u64,
+    min_amount_out: u64,
+) -> Result<()> {
+    check_stoptap(&ctx.accounts.treasury)?;
+    msg!("Position: {}", index);
+
+    let now = Clock::get()?.unix_timestamp;
+    update_reward_streams(&mut ctx.accounts.a_pool_pda, now)?;
+    sync_lp_balance(&mut ctx.accounts.a_pool_pda, &mut ctx.accounts.provider, ctx.accounts.position_lp_account.amount, now)?;
+
+    if ctx.accounts.provider.lp_balance - ctx.accounts.provider.locked_lp < amount {
+        return err!(SpiceError::LiquidityLocked);
+    }
+
+    check_pools(
+        &ctx.accounts.a_pool_pda,
+        &ctx.accounts.b_pool_pda,
+        ctx.accounts.token_a_pyth_price_feed_account.key(),
+        ctx.accounts.token_b_pyth_price_feed_account.key()
+    )?;
+
+    let price_a = ctx.accounts.token_a_pyth_price_feed_account.price_message.price as u64;
+    let price_b = ctx.accounts.token_b_pyth_price_feed_account.price_message.price as u64;
+
+    msg!("Input price: {}", price_a);
+    msg!("Output price: {}", price_b);
+
+    let epoch = Clock::get()?.epoch;
+    let (withdrawal, quote) = withdraw_into(
+        &mut ctx.accounts.a_pool_pda,
+        &mut ctx.accounts.b_pool_pda,
+        amount,
+        (price_a, price_b),
+        (ctx.accounts.token_a_mint.decimals, ctx.accounts.token_b_mint.decimals),
+        &ctx.accounts.treasury,
+        epoch
+    )?;
+
+    msg!("Withdrawal: {}", withdrawal.amount);
+    msg!("Withdrawal fee: {}", withdrawal.fee);
+    msg!("Input fee: {}", quote.input_fee);
+    msg!("Raw output: {}", quote.raw_amount_out);
+    msg!("Net output: {}", quote.amount_out);
+    msg!("Protocol fee: {}", quote.protocol_fee);
+    msg!("Rebate: {}", quote.rebate);
+
+    if quote.amount_out < min_amount_out {
+        return Err(SpiceError::HighSlippage.into());
+    }
+
+    // Update Provider
+    ctx.accounts.provider.lp_balance -= amount;
+
+    // Burn LP
+    token::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token::Burn {
+                mint: ctx.accounts.lp_token_mint_pda.to_account_info(),
+                from: ctx.accounts.position_lp_account.to_account_info(),
+                authority: ctx.accounts.signer.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    // Transfer from Treasury to User
+    let seeds = &[SPICE_SEED.as_bytes(), TREASURY_SEED.as_bytes(), &[ctx.bumps.treasury]];
+    let signer = [&seeds[..]];
+
+    transfer_from_treasury(
+        ctx.accounts.signer.to_account_info(),
+        ctx.accounts.signer_b_ata.clone(),
+        ctx.accounts.treasury.clone(),
+        ctx.accounts.treasury_b_ata.clone(),
+        ctx.accounts.token_b_mint.key(),
+        quote.amount_out,
+        ctx.accounts.token_program.clone(),
+        &signer)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(index: u8)]
+pub struct DecreaseLiquidityIntoInstructionAccounts<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub token_a_mint: Account<'info, Mint>,
+
+    #[account(constraint = token_b_mint.key() != token_a_mint.key())]
+    pub token_b_mint: Account<'info, Mint>,
+
+    /// CHECK: pool_pda.pyth
+    pub token_a_pyth_price_feed_account: Account<'info, PriceUpdateV2>,
+
+    /// CHECK: pool_pda.pyth
+    pub token_b_pyth_price_feed_account: Account<'info, PriceUpdateV2>,
+
+    #[account(mut, seeds = [POOL_SEED.as_bytes(), token_a_mint.key().as_ref()], bump)]
+    pub a_pool_pda: Box<Account<'info, Pool>>,
+
+    #[account(mut, seeds = [POOL_SEED.as_bytes(), token_b_mint.key().as_ref()], bump)]
+    pub b_pool_pda: Box<Account<'info, Pool>>,
+
+    #[account(mut, seeds = [MINT_SEED.as_bytes(), a_pool_pda.key().as_ref()], bump)]
+    pub lp_token_mint_pda: Account<'info, Mint>,
+
+    #[account(mut, seeds = [PROVIDER_SEED.as_bytes(), a_pool_pda.key().as_ref(), signer.key().as_ref(), &[index]], bump)]
+    pub provider: Box<Account<'info, Provider>>,
+
+    #[account(mut, seeds = [POSITION_SEED.as_bytes(), provider.key().as_ref()], bump, token::mint = lp_token_mint_pda, token::authority = signer)]
+    pub position_lp_account: Account<'info, TokenAccount>,
+
+    #[account(mut, token::authority = signer, token::mint = token_b_mint)]
+    pub signer_b_ata: Option<Account<'info, TokenAccount>>,
+
+    /// CHECK:
+    #[account(mut, seeds = [SPICE_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury: Account<'info, Settings>,
+
+    #[account(mut, token::authority = treasury, token::mint = token_b_mint)]
+    pub treasury_b_ata: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
\ No newline at end of file
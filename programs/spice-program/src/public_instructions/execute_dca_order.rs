@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+
+use crate::{
+    constants::{DCA_SEED, POOL_SEED, SPICE_SEED, TREASURY_SEED},
+    errors::SpiceError,
+    functions::{check_pools, check_stoptap, next_dca_slice, quote_swap, record_dca_slice, settle_swap, transfer_from_treasury},
+    states::{DcaOrder, Pool, Settings},
+};
+
+pub fn execute_dca_order(ctx: Context<ExecuteDcaOrderInstructionAccounts>) -> Result<()> {
+    check_stoptap(&ctx.accounts.treasury)?;
+
+    let order = &mut ctx.accounts.dca_order;
+    let pool_a = &mut ctx.accounts.a_pool_pda;
+    let pool_b = &mut ctx.accounts.b_pool_pda;
+
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+    let epoch = clock.epoch;
+    let amount_in = next_dca_slice(order, now)?;
+
+    check_pools(
+        pool_a,
+        pool_b,
+        ctx.accounts.token_a_pyth_price_feed_account.key(),
+        ctx.accounts.token_b_pyth_price_feed_account.key()
+    )?;
+
+    let price_a = ctx.accounts.token_a_pyth_price_feed_account.price_message.price as u64;
+    let price_b = ctx.accounts.token_b_pyth_price_feed_account.price_message.price as u64;
+
+    let quote = quote_swap(
+        pool_a,
+        pool_b,
+        amount_in,
+        (price_a, price_b),
+        (ctx.accounts.token_a_mint.decimals, ctx.accounts.token_b_mint.decimals),
+        0,
+        epoch
+    )?;
+
+    msg!("Slice: {}/{}", order.executed_slices + 1, order.slices);
+    msg!("Slice input: {}", amount_in);
+    msg!("Net output: {}", quote.amount_out);
+
+    record_dca_slice(order, amount_in, quote.amount_out, now)?;
+
+    if quote.amount_out > pool_b.current_liquidity {
+        return Err(SpiceError::InsufficientLiquidity.into());
+    }
+
+    settle_swap(
+        pool_a,
+        pool_b,
+        amount_in,
+        &quote,
+        &ctx.accounts.treasury,
+        epoch
+    )?;
+
+    // Transfer from Treasury to Owner
+    let seeds = &[SPICE_SEED.as_bytes(), TREASURY_SEED.as_bytes(), &[ctx.bumps.treasury]];
+    let signer = &[&seeds[..]];
+
+    transfer_from_treasury(
+        ctx.accounts.owner.to_account_info(), 
+        ctx.accounts.owner_b_ata.clone(), 
+        ctx.accounts.treasury.clone(), 
+        ctx.accounts.treasury_b_ata.clone(), 
+        ctx.accounts.token_b_mint.key(), 
+        quote.amount_out, 
+        ctx.accounts.token_program.clone(), 
+        signer)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ExecuteDcaOrderInstructionAccounts<'info> {
+    pub signer: Signer<'info>,
+
+    /// CHECK: dca_order.owner
+    #[account(mut)]
+    pub owner: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        has_one = owner,
+        seeds = [DCA_SEED.as_bytes(), owner.key().as_ref(), &dca_order.id.to_le_bytes()],
+        bump
+    )]
+    pub dca_order: Account<'info, DcaOrder>,
+
+    #[account(address = dca_order.input_mint)]
+    pub token_a_mint: Account<'info, Mint>,
+
+    #[account(address = dca_order.output_mint)]
+    pub token_b_mint: Account<'info, Mint>,
+
+    /// CHECK: pool_pda.pyth
+    pub token_a_pyth_price_feed_account: Account<'info, PriceUpdateV2>,
+
+    /// CHECK: pool_pda.pyth
+    pub token_b_pyth_price_feed_account: Account<'info, PriceUpdateV2>,
+
+    #[account(mut, seeds = [POOL_SEED.as_bytes(), token_a_mint.key().as_ref()], bump)]
+    pub a_pool_pda: Account<'info, Pool>,
+
+    #[account(mut, seeds = [POOL_SEED.as_bytes(), token_b_mint.key().as_ref()], bump)]
+    pub b_pool_pda: Account<'info, Pool>,
+
+    #[account(mut, token::authority = owner, token::mint = token_b_mint)]
+    pub owner_b_ata: Option<Account<'info, TokenAccount>>,
+
+    /// CHECK:
+    #[account(mut, seeds = [SPICE_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury: Account<'info, Settings>,
+
+    #[account(mut, token::authority = treasury, token::mint = token_b_mint)]
+    pub treasury_b_ata: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
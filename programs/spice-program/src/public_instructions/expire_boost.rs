@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::{POOL_SEED, PROVIDER_SEED}, functions::{expire_position_boost, update_reward_streams}, states::{Pool, Provider}};
+
+// Permissionless crank, drops a run out lock boost from the pool total without touching the position's LP
+pub fn expire_boost(ctx: Context<ExpireBoostInstructionAccounts>, index: u8) -> Result<()> {
+    msg!("Position: {}", index);
+
+    let now = Clock::get()?.unix_timestamp;
+    update_reward_streams(&mut ctx.accounts.pool_pda, now)?;
+
+    let expired = expire_position_boost(&mut ctx.accounts.pool_pda, &mut ctx.accounts.provider, now)?;
+    msg!("Expired boost: {}", expired);
+    msg!("Total boost: {}", ctx.accounts.pool_pda.total_lp_boost);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(index: u8)]
+pub struct ExpireBoostInstructionAccounts<'info> {
+    pub signer: Signer<'info>,
+
+    /// CHECK: position owner
+    pub owner: AccountInfo<'info>,
+
+    /// CHECK:
+    pub mint: AccountInfo<'info>,
+
+    #[account(mut, seeds = [POOL_SEED.as_bytes(), mint.key().as_ref()], bump)]
+    pub pool_pda: Account<'info, Pool>,
+
+    #[account(mut, seeds = [PROVIDER_SEED.as_bytes(), pool_pda.key().as_ref(), owner.key().as_ref(), &[index]], bump)]
+    pub provider: Account<'info, Provider>,
+}
\ No newline at end of file
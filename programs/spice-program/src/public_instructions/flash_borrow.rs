@@ -0,0 +1,86 @@
+use anchor_lang::{prelude::*, solana_program::sysvar::instructions::{self, load_current_index_checked, load_instruction_at_checked}};
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::{
+    constants::{POOL_SEED, SPICE_SEED, TREASURY_SEED}, errors::SpiceError, functions::{check_flash_repay, check_stoptap, transfer_from_treasury}, states::{Pool, Settings}
+};
+
+pub fn flash_borrow(ctx: Context<FlashBorrowInstructionAccounts>, amount: u64) -> Result<()> {
+    check_stoptap(&ctx.accounts.treasury)?;
+
+    let pool = &mut ctx.accounts.pool_pda;
+
+    if !pool.is_active {
+        return Err(SpiceError::PoolNotActive.into());
+    }
+    if pool.flash_loan_amount > 0 {
+        return Err(SpiceError::FlashLoanActive.into());
+    }
+    if amount > pool.current_liquidity {
+        return Err(SpiceError::InsufficientLiquidity.into());
+    }
+
+    // Repayment must follow in the same transaction
+    let instructions_sysvar = ctx.accounts.instructions.to_account_info();
+    let current_index = load_current_index_checked(&instructions_sysvar)? as usize;
+
+    let current_instruction = load_instruction_at_checked(current_index, &instructions_sysvar)?;
+    if current_instruction.program_id != crate::ID {
+        return Err(SpiceError::FlashLoanCpi.into());
+    }
+
+    let following_instructions = (current_index + 1..)
+        .map_while(|index| load_instruction_at_checked(index, &instructions_sysvar).ok());
+    check_flash_repay(following_instructions, &pool.key())?;
+
+    msg!("Flash loan: {}", amount);
+
+    // Update Pool
+    pool.flash_loan_amount = amount;
+    pool.current_liquidity -= amount;
+
+    // Transfer from Treasury to User
+    let seeds = &[SPICE_SEED.as_bytes(), TREASURY_SEED.as_bytes(), &[ctx.bumps.treasury]];
+    let signer = [&seeds[..]];
+
+    transfer_from_treasury(
+        ctx.accounts.signer.to_account_info(), 
+        ctx.accounts.signer_ata.clone(), 
+        ctx.accounts.treasury.clone(), 
+        ctx.accounts.treasury_ata.clone(), 
+        ctx.accounts.mint.key(), 
+        amount, 
+        ctx.accounts.token_program.clone(), 
+        &signer)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FlashBorrowInstructionAccounts<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// CHECK:
+    pub mint: AccountInfo<'info>,
+
+    #[account(mut, token::authority = signer, token::mint = mint)]
+    pub signer_ata: Option<Account<'info, TokenAccount>>,
+
+    #[account(mut, seeds = [POOL_SEED.as_bytes(), mint.key().as_ref()], bump)]
+    pub pool_pda: Account<'info, Pool>,
+
+    /// CHECK:
+    #[account(mut, seeds = [SPICE_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury: Account<'info, Settings>,
+
+    #[account(mut, token::authority = treasury, token::mint = mint)]
+    pub treasury_ata: Option<Account<'info, TokenAccount>>,
+
+    /// CHECK: instructions sysvar
+    #[account(address = instructions::ID)]
+    pub instructions: AccountInfo<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
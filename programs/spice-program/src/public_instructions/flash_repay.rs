@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::{
+    constants::{POOL_SEED, SPICE_SEED, TREASURY_SEED}, errors::SpiceError, functions::{cover_deficit, distribute_fee, flash_loan_fee, transfer_to_treasury}, states::{Pool, Settings}
+};
+
+pub fn flash_repay(ctx: Context<FlashRepayInstructionAccounts>) -> Result<()> {
+    let amount = ctx.accounts.pool_pda.flash_loan_amount;
+    if amount == 0 {
+        return Err(SpiceError::NoFlashLoan.into());
+    }
+
+    let fee = flash_loan_fee(ctx.accounts.pool_pda.flash_loan_fee, amount)?;
+
+    msg!("Flash loan: {}", amount);
+    msg!("Flash loan fee: {}", fee);
+
+    transfer_to_treasury(
+        ctx.accounts.signer.clone(), 
+        ctx.accounts.signer_ata.clone(), 
+        ctx.accounts.treasury.clone(), 
+        ctx.accounts.treasury_ata.clone(), 
+        ctx.accounts.mint.key(), 
+        amount + fee, 
+        ctx.accounts.token_program.clone(), 
+        ctx.accounts.system_program.clone())?;
+
+    // Update Pool
+    let pool = &mut ctx.accounts.pool_pda;
+
+    distribute_fee(pool, fee, &ctx.accounts.treasury)?;
+
+    pool.flash_loan_amount = 0;
+    pool.current_liquidity += amount;
+
+    cover_deficit(pool)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FlashRepayInstructionAccounts<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// CHECK:
+    pub mint: AccountInfo<'info>,
+
+    #[account(mut, token::authority = signer, token::mint = mint)]
+    pub signer_ata: Option<Account<'info, TokenAccount>>,
+
+    #[account(mut, seeds = [POOL_SEED.as_bytes(), mint.key().as_ref()], bump)]
+    pub pool_pda: Account<'info, Pool>,
+
+    /// CHECK:
+    #[account(mut, seeds = [SPICE_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury: Account<'info, Settings>,
+
+    #[account(mut, token::authority = treasury, token::mint = mint)]
+    pub treasury_ata: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token::{self, Mint, Token, TokenAccount}};
+
+use crate::{constants::{POOL_SEED, SPICE_SEED, TREASURY_SEED}, functions::{add_reward_stream, check_stoptap, free_reward_slot, refund_reward_funder, refund_replaced_stream, update_reward_streams}, states::{Pool, Settings}};
+
+// Permissionless, anyone can pay the pool's providers in any SPL token
+pub fn fund_incentive(ctx: Context<FundIncentiveInstructionAccounts>, rate: u64, start: i64, end: i64) -> Result<()> {
+    check_stoptap(&ctx.accounts.treasury)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    update_reward_streams(&mut ctx.accounts.pool_pda, now)?;
+
+    let slot = free_reward_slot(&ctx.accounts.pool_pda, now)?;
+
+    // Refund the stream this one replaces
+    let replaced = ctx.accounts.pool_pda.reward_streams[slot];
+    let refund = refund_replaced_stream(&mut ctx.accounts.pool_pda, slot, now)?;
+    msg!("Replaced stream refund: {}", refund);
+
+    let seeds = &[SPICE_SEED.as_bytes(), TREASURY_SEED.as_bytes(), &[ctx.bumps.treasury]];
+    let signer = [&seeds[..]];
+
+    refund_reward_funder(
+        &replaced,
+        ctx.accounts.replaced_funder_ata.clone(),
+        ctx.accounts.treasury.clone(),
+        ctx.accounts.replaced_treasury_ata.clone(),
+        refund,
+        ctx.accounts.token_program.clone(),
+        &signer)?;
+
+    let funding = add_reward_stream(
+        &mut ctx.accounts.pool_pda,
+        slot,
+        ctx.accounts.signer.key(),
+        ctx.accounts.reward_mint.key(),
+        rate,
+        (start, end),
+        now,
+    )?;
+
+    msg!("Reward stream: {}", ctx.accounts.pool_pda.reward_stream_nonce);
+    msg!("Slot: {}", slot);
+    msg!("Funding: {}", funding);
+
+    // Escrow incentive in Treasury
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.signer_reward_ata.to_account_info(),
+                to: ctx.accounts.treasury_reward_ata.to_account_info(),
+                authority: ctx.accounts.signer.to_account_info(),
+            },
+        ),
+        funding,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FundIncentiveInstructionAccounts<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// CHECK:
+    pub mint: AccountInfo<'info>,
+
+    #[account(mut, seeds = [POOL_SEED.as_bytes(), mint.key().as_ref()], bump)]
+    pub pool_pda: Account<'info, Pool>,
+
+    pub reward_mint: Account<'info, Mint>,
+
+    #[account(mut, token::authority = signer, token::mint = reward_mint)]
+    pub signer_reward_ata: Account<'info, TokenAccount>,
+
+    #[account(seeds = [SPICE_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury: Account<'info, Settings>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = reward_mint,
+        associated_token::authority = treasury,
+    )]
+    pub treasury_reward_ata: Account<'info, TokenAccount>,
+
+    // Only needed when the slot still holds an unrefunded stream
+    #[account(mut)]
+    pub replaced_funder_ata: Option<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub replaced_treasury_ata: Option<Account<'info, TokenAccount>>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
\ No newline at end of file
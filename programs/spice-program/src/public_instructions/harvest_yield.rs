@@ -1,23 +1,21 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount};
+use anchor_spl::{associated_token::AssociatedToken, token::{self, Mint, Token, TokenAccount}};
 
-use crate::{constants::{POOL_SEED, PROVIDER_SEED, SOL, SPICE_SEED, TREASURY_SEED}, errors::SpiceError, functions::{check_stoptap, calculate_yield}, states::{Pool, Provider, Settings}};
+use crate::{constants::{MINT_SEED, POOL_SEED, POSITION_SEED, PROVIDER_SEED, SOL, SPICE_SEED, TREASURY_SEED}, errors::SpiceError, functions::{check_stoptap, sync_lp_balance, update_reward_streams}, states::{Pool, Provider, Settings}};
 
 
-pub fn harvest_yield(ctx: Context<HarvestYieldInstructionAccounts>) -> Result<()> {
+pub fn harvest_yield(ctx: Context<HarvestYieldInstructionAccounts>, index: u8) -> Result<()> {
     check_stoptap(&ctx.accounts.treasury)?;
+    msg!("Position: {}", index);
     
     // Calculate Income
-    let total_lp = ctx.accounts.pool_pda.initial_liquidity;
-    let provider_pending_claim = ctx.accounts.provider.pending_claim;
-    let provider_lp_balance = ctx.accounts.provider.lp_balance;
-    let provider_last_cumulative_yield = ctx.accounts.provider.last_cumulative_yield;
-    let cumulative_yield = ctx.accounts.pool_pda.cumulative_yield;
+    let now = Clock::get()?.unix_timestamp;
+    update_reward_streams(&mut ctx.accounts.pool_pda, now)?;
+    sync_lp_balance(&mut ctx.accounts.pool_pda, &mut ctx.accounts.provider, ctx.accounts.position_lp_account.amount, now)?;
 
-    let provider_income: u64 = calculate_yield(cumulative_yield, total_lp, provider_lp_balance, provider_last_cumulative_yield)? + provider_pending_claim;
+    let provider_income: u64 = ctx.accounts.provider.pending_claim;
 
     // Update Provider
-    ctx.accounts.provider.last_cumulative_yield = ctx.accounts.pool_pda.cumulative_yield;
     ctx.accounts.provider.pending_claim = 0;
 
     
@@ -45,6 +43,7 @@ pub fn harvest_yield(ctx: Context<HarvestYieldInstructionAccounts>) -> Result<()
 }
 
 #[derive(Accounts)]
+#[instruction(index: u8)]
 pub struct HarvestYieldInstructionAccounts<'info> {
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -58,9 +57,15 @@ pub struct HarvestYieldInstructionAccounts<'info> {
     #[account(mut, seeds = [POOL_SEED.as_bytes(), mint.key().as_ref()], bump)]
     pub pool_pda: Account<'info, Pool>,
 
-    #[account(mut, seeds = [PROVIDER_SEED.as_bytes(), pool_pda.key().as_ref(), signer.key().as_ref()], bump)]
+    #[account(seeds = [MINT_SEED.as_bytes(), pool_pda.key().as_ref()], bump)]
+    pub lp_token_mint_pda: Account<'info, Mint>,
+
+    #[account(mut, seeds = [PROVIDER_SEED.as_bytes(), pool_pda.key().as_ref(), signer.key().as_ref(), &[index]], bump)]
     pub provider: Account<'info, Provider>,
 
+    #[account(seeds = [POSITION_SEED.as_bytes(), provider.key().as_ref()], bump, token::mint = lp_token_mint_pda, token::authority = signer)]
+    pub position_lp_account: Account<'info, TokenAccount>,
+
     /// CHECK:
     #[account(mut, seeds = [SPICE_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
     pub treasury: Account<'info, Settings>,
@@ -68,6 +73,7 @@ pub struct HarvestYieldInstructionAccounts<'info> {
     #[account(mut, token::authority = treasury, token::mint = mint)]
     pub treasury_ata: Option<Account<'info, TokenAccount>>,
 
+    pub associated_token_program: Program<'info, AssociatedToken>,
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
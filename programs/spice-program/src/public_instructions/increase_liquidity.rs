@@ -5,15 +5,20 @@ use anchor_spl::{
 };
 
 use crate::{
-    constants::{MINT_SEED, POOL_SEED, PROVIDER_SEED, SPICE_SEED, TREASURY_SEED}, functions::{check_stoptap, calculate_yield, transfer_to_treasury}, states::{Pool, Provider, Settings}
+    constants::{MAX_REWARD_STREAMS, MINT_SEED, POOL_SEED, POSITION_REGISTRY_SEED, POSITION_SEED, PROVIDER_SEED, SPICE_SEED, TREASURY_SEED}, functions::{check_stoptap, deposit_position, register_position, transfer_to_treasury, update_reward_streams}, states::{Pool, PositionRegistry, Provider, Settings}
 };
 
 pub fn increase_liquidity(
     ctx: Context<IncreaseLiquidityInstructionAccounts>,
+    index: u8,
     amount: u64,
 ) -> Result<()> {
     check_stoptap(&ctx.accounts.treasury)?;
 
+    msg!("Position: {}", index);
+    register_position(&mut ctx.accounts.position_registry, index)?;
+    ctx.accounts.position_registry.owner = ctx.accounts.beneficiary.key();
+
     transfer_to_treasury(
         ctx.accounts.signer.clone(), 
         ctx.accounts.signer_ata.clone(), 
@@ -25,6 +30,19 @@ pub fn increase_liquidity(
         ctx.accounts.system_program.clone())?;
 
 
+    let now = Clock::get()?.unix_timestamp;
+    update_reward_streams(&mut ctx.accounts.pool_pda, now)?;
+    let lp_amount = deposit_position(
+        &mut ctx.accounts.pool_pda,
+        &mut ctx.accounts.provider,
+        ctx.accounts.beneficiary.key(),
+        index,
+        amount,
+        ctx.accounts.position_lp_account.amount,
+        now,
+    )?;
+    msg!("LP minted: {}", lp_amount);
+
     let pool_pda_key = ctx.accounts.pool_pda.key();
     let seeds = &[MINT_SEED.as_bytes(), pool_pda_key.as_ref(), &[ctx.bumps.lp_token_mint_pda]];
     let signer = [&seeds[..]];
@@ -34,36 +52,19 @@ pub fn increase_liquidity(
             ctx.accounts.token_program.to_account_info(),
             MintTo {
                 authority: ctx.accounts.lp_token_mint_pda.to_account_info(),
-                to: ctx.accounts.signer_lp_ata.to_account_info(),
+                to: ctx.accounts.position_lp_account.to_account_info(),
                 mint: ctx.accounts.lp_token_mint_pda.to_account_info(),
             },
             &signer,
         ),
-        amount,
+        lp_amount,
     )?;
 
-
-    let total_lp = ctx.accounts.pool_pda.initial_liquidity;
-    let provider_lp_balance = ctx.accounts.provider.lp_balance;
-    let provider_last_cumulative_yield = ctx.accounts.provider.last_cumulative_yield;
-    let cumulative_yield = ctx.accounts.pool_pda.cumulative_yield;
-
-    let provider_income: u64 = calculate_yield(cumulative_yield, total_lp, provider_lp_balance, provider_last_cumulative_yield)?;
-
-    // Update provider
-    ctx.accounts.provider.address = ctx.accounts.signer.key();
-    ctx.accounts.provider.last_cumulative_yield = ctx.accounts.pool_pda.cumulative_yield;
-    ctx.accounts.provider.lp_balance += amount;
-    ctx.accounts.provider.pending_claim += provider_income;
-
-    // Update pool
-    ctx.accounts.pool_pda.initial_liquidity += amount;
-    ctx.accounts.pool_pda.current_liquidity += amount;
-
     Ok(())
 }
 
 #[derive(Accounts)]
+#[instruction(index: u8)]
 pub struct IncreaseLiquidityInstructionAccounts<'info> {
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -74,13 +75,8 @@ pub struct IncreaseLiquidityInstructionAccounts<'info> {
     #[account(mut, token::mint = mint)]
     pub signer_ata: Option<Account<'info, TokenAccount>>,
 
-    #[account(
-        init_if_needed,
-        payer = signer,
-        associated_token::mint = lp_token_mint_pda,
-        associated_token::authority = signer,
-    )]
-    pub signer_lp_ata: Account<'info, TokenAccount>,
+    /// CHECK: position owner, the signer funds the deposit
+    pub beneficiary: AccountInfo<'info>,
 
     #[account(mut, seeds = [POOL_SEED.as_bytes(), mint.key().as_ref()], bump)]
     pub pool_pda: Account<'info, Pool>,
@@ -91,12 +87,31 @@ pub struct IncreaseLiquidityInstructionAccounts<'info> {
     #[account(
         init_if_needed,
         payer = signer,
-        seeds = [PROVIDER_SEED.as_bytes(), pool_pda.key().as_ref(), signer.key().as_ref()],
+        seeds = [PROVIDER_SEED.as_bytes(), pool_pda.key().as_ref(), beneficiary.key().as_ref(), &[index]],
         bump,
-        space = 8 + 32 + 8 + 8 + 8,
+        space = 8 + 32 + 1 + 8 + 8 + 8 + 1 + 8 + 8 + 8 + 8 + MAX_REWARD_STREAMS * 32,
     )]
     pub provider: Account<'info, Provider>,
 
+    #[account(
+        init_if_needed,
+        payer = signer,
+        seeds = [POSITION_SEED.as_bytes(), provider.key().as_ref()],
+        bump,
+        token::mint = lp_token_mint_pda,
+        token::authority = beneficiary,
+    )]
+    pub position_lp_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        seeds = [POSITION_REGISTRY_SEED.as_bytes(), pool_pda.key().as_ref(), beneficiary.key().as_ref()],
+        bump,
+        space = 8 + 32 + 8,
+    )]
+    pub position_registry: Account<'info, PositionRegistry>,
+
     #[account(seeds = [SPICE_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
     pub treasury: Account<'info, Settings>,
 
@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{mint_to, Mint, MintTo, Token, TokenAccount},
+};
+
+use crate::{
+    constants::{MINT_SEED, POOL_SEED, POSITION_SEED, PROVIDER_SEED, SPICE_SEED, TREASURY_SEED}, functions::{check_stoptap, compound_provider, sync_lp_balance, take_keeper_tip, transfer_from_treasury, update_reward_streams}, states::{Pool, Provider, Settings}
+};
+
+pub fn keeper_compound(ctx: Context<KeeperCompoundInstructionAccounts>, index: u8) -> Result<()> {
+    check_stoptap(&ctx.accounts.treasury)?;
+    msg!("Position: {}", index);
+
+    let now = Clock::get()?.unix_timestamp;
+    update_reward_streams(&mut ctx.accounts.pool_pda, now)?;
+    sync_lp_balance(&mut ctx.accounts.pool_pda, &mut ctx.accounts.provider, ctx.accounts.position_lp_account.amount, now)?;
+
+    let tip = take_keeper_tip(&mut ctx.accounts.provider)?;
+    msg!("Keeper tip: {}", tip);
+
+    // Update pool and provider
+    let lp_amount = compound_provider(&mut ctx.accounts.pool_pda, &mut ctx.accounts.provider, now)?;
+    msg!("LP minted: {}", lp_amount);
+
+    let pool_pda_key = ctx.accounts.pool_pda.key();
+    let seeds = &[MINT_SEED.as_bytes(), pool_pda_key.as_ref(), &[ctx.bumps.lp_token_mint_pda]];
+    let signer = [&seeds[..]];
+
+    mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                authority: ctx.accounts.lp_token_mint_pda.to_account_info(),
+                to: ctx.accounts.position_lp_account.to_account_info(),
+                mint: ctx.accounts.lp_token_mint_pda.to_account_info(),
+            },
+            &signer,
+        ),
+        lp_amount,
+    )?;
+
+    // Transfer tip from Treasury to Keeper
+    let seeds = &[SPICE_SEED.as_bytes(), TREASURY_SEED.as_bytes(), &[ctx.bumps.treasury]];
+    let signer = [&seeds[..]];
+
+    transfer_from_treasury(
+        ctx.accounts.signer.to_account_info(),
+        ctx.accounts.signer_ata.clone(),
+        ctx.accounts.treasury.clone(),
+        ctx.accounts.treasury_ata.clone(),
+        ctx.accounts.mint.key(),
+        tip,
+        ctx.accounts.token_program.clone(),
+        &signer)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(index: u8)]
+pub struct KeeperCompoundInstructionAccounts<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// CHECK: position owner
+    pub owner: AccountInfo<'info>,
+
+    /// CHECK:
+    pub mint: AccountInfo<'info>,
+
+    #[account(mut, token::authority = signer, token::mint = mint)]
+    pub signer_ata: Option<Account<'info, TokenAccount>>,
+
+    #[account(mut, seeds = [POOL_SEED.as_bytes(), mint.key().as_ref()], bump)]
+    pub pool_pda: Account<'info, Pool>,
+
+    #[account(mut, seeds = [MINT_SEED.as_bytes(), pool_pda.key().as_ref()], bump)]
+    pub lp_token_mint_pda: Account<'info, Mint>,
+
+    #[account(mut, seeds = [PROVIDER_SEED.as_bytes(), pool_pda.key().as_ref(), owner.key().as_ref(), &[index]], bump)]
+    pub provider: Account<'info, Provider>,
+
+    #[account(mut, seeds = [POSITION_SEED.as_bytes(), provider.key().as_ref()], bump, token::mint = lp_token_mint_pda, token::authority = owner)]
+    pub position_lp_account: Account<'info, TokenAccount>,
+
+    /// CHECK:
+    #[account(mut, seeds = [SPICE_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury: Account<'info, Settings>,
+
+    #[account(mut, token::authority = treasury, token::mint = mint)]
+    pub treasury_ata: Option<Account<'info, TokenAccount>>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
\ No newline at end of file
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token::{Mint, Token, TokenAccount}};
+
+use crate::{constants::{MINT_SEED, POOL_SEED, POSITION_SEED, PROVIDER_SEED, SPICE_SEED, TREASURY_SEED}, functions::{check_stoptap, sync_lp_balance, take_keeper_tip, transfer_from_treasury, update_reward_streams}, states::{Pool, Provider, Settings}};
+
+
+pub fn keeper_harvest(ctx: Context<KeeperHarvestInstructionAccounts>, index: u8) -> Result<()> {
+    check_stoptap(&ctx.accounts.treasury)?;
+    msg!("Position: {}", index);
+
+    // Calculate Income
+    let now = Clock::get()?.unix_timestamp;
+    update_reward_streams(&mut ctx.accounts.pool_pda, now)?;
+    sync_lp_balance(&mut ctx.accounts.pool_pda, &mut ctx.accounts.provider, ctx.accounts.position_lp_account.amount, now)?;
+
+    let tip = take_keeper_tip(&mut ctx.accounts.provider)?;
+    let provider_income: u64 = ctx.accounts.provider.pending_claim;
+    msg!("Keeper tip: {}", tip);
+
+    // Update Provider
+    ctx.accounts.provider.pending_claim = 0;
+
+    // Transfer from Treasury to Owner and Keeper
+    let seeds = &[SPICE_SEED.as_bytes(), TREASURY_SEED.as_bytes(), &[ctx.bumps.treasury]];
+    let signer = [&seeds[..]];
+
+    transfer_from_treasury(
+        ctx.accounts.owner.to_account_info(),
+        ctx.accounts.owner_ata.clone(),
+        ctx.accounts.treasury.clone(),
+        ctx.accounts.treasury_ata.clone(),
+        ctx.accounts.mint.key(),
+        provider_income,
+        ctx.accounts.token_program.clone(),
+        &signer)?;
+
+    transfer_from_treasury(
+        ctx.accounts.signer.to_account_info(),
+        ctx.accounts.signer_ata.clone(),
+        ctx.accounts.treasury.clone(),
+        ctx.accounts.treasury_ata.clone(),
+        ctx.accounts.mint.key(),
+        tip,
+        ctx.accounts.token_program.clone(),
+        &signer)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(index: u8)]
+pub struct KeeperHarvestInstructionAccounts<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// CHECK: position owner
+    #[account(mut)]
+    pub owner: AccountInfo<'info>,
+
+    /// CHECK:
+    pub mint: AccountInfo<'info>,
+
+    #[account(mut, token::authority = signer, token::mint = mint)]
+    pub signer_ata: Option<Account<'info, TokenAccount>>,
+
+    #[account(mut, token::authority = owner, token::mint = mint)]
+    pub owner_ata: Option<Account<'info, TokenAccount>>,
+
+    #[account(mut, seeds = [POOL_SEED.as_bytes(), mint.key().as_ref()], bump)]
+    pub pool_pda: Account<'info, Pool>,
+
+    #[account(seeds = [MINT_SEED.as_bytes(), pool_pda.key().as_ref()], bump)]
+    pub lp_token_mint_pda: Account<'info, Mint>,
+
+    #[account(mut, seeds = [PROVIDER_SEED.as_bytes(), pool_pda.key().as_ref(), owner.key().as_ref(), &[index]], bump)]
+    pub provider: Account<'info, Provider>,
+
+    #[account(seeds = [POSITION_SEED.as_bytes(), provider.key().as_ref()], bump, token::mint = lp_token_mint_pda, token::authority = owner)]
+    pub position_lp_account: Account<'info, TokenAccount>,
+
+    /// CHECK:
+    #[account(mut, seeds = [SPICE_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury: Account<'info, Settings>,
+
+    #[account(mut, token::authority = treasury, token::mint = mint)]
+    pub treasury_ata: Option<Account<'info, TokenAccount>>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
\ No newline at end of file
@@ -0,0 +1,120 @@
+use anchor_lang::{prelude::*, system_program, Discriminator};
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+
+use crate::{
+    constants::{LEGACY_PROVIDER_SPACE, MAX_REWARD_STREAMS, MINT_SEED, POOL_SEED, POSITION_REGISTRY_SEED, POSITION_SEED, PROVIDER_SEED}, functions::{migrate_legacy_provider, register_position, update_reward_streams}, states::{LegacyProvider, Pool, PositionRegistry, Provider}
+};
+
+// Moves a provider created before positions were indexed into the given position,
+// together with the LP it still holds in its wallet
+pub fn migrate_provider(ctx: Context<MigrateProviderInstructionAccounts>, index: u8) -> Result<()> {
+    let legacy_info = ctx.accounts.legacy_provider.to_account_info();
+
+    if legacy_info.owner != &crate::ID {
+        return Err(ErrorCode::AccountOwnedByWrongProgram.into());
+    }
+    if legacy_info.data_len() != LEGACY_PROVIDER_SPACE {
+        return Err(ErrorCode::AccountDidNotDeserialize.into());
+    }
+
+    let legacy = {
+        let data = legacy_info.try_borrow_data()?;
+        if data[..8] != Provider::DISCRIMINATOR {
+            return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+        }
+        LegacyProvider::deserialize(&mut &data[8..])?
+    };
+
+    // Legacy LP was freely transferable, only what is still in the wallet can back the position
+    let lp_amount = ctx.accounts.signer_lp_ata.amount.min(legacy.lp_balance);
+
+    msg!("Position: {}", index);
+    msg!("Legacy LP balance: {}", legacy.lp_balance);
+    msg!("LP moved: {}", lp_amount);
+
+    let now = Clock::get()?.unix_timestamp;
+    update_reward_streams(&mut ctx.accounts.pool_pda, now)?;
+
+    let provider = migrate_legacy_provider(&ctx.accounts.pool_pda, &legacy, index, lp_amount, now)?;
+    ctx.accounts.provider.set_inner(provider);
+
+    register_position(&mut ctx.accounts.position_registry, index)?;
+    ctx.accounts.position_registry.owner = ctx.accounts.signer.key();
+
+    if lp_amount > 0 {
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                token::Transfer {
+                    from: ctx.accounts.signer_lp_ata.to_account_info(),
+                    to: ctx.accounts.position_lp_account.to_account_info(),
+                    authority: ctx.accounts.signer.to_account_info(),
+                },
+            ),
+            lp_amount,
+        )?;
+    }
+
+    // Close the legacy account, its rent goes back to the owner
+    let signer_info = ctx.accounts.signer.to_account_info();
+    **signer_info.try_borrow_mut_lamports()? += legacy_info.lamports();
+    **legacy_info.try_borrow_mut_lamports()? = 0;
+    legacy_info.assign(&system_program::ID);
+    legacy_info.realloc(0, false)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(index: u8)]
+pub struct MigrateProviderInstructionAccounts<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// CHECK:
+    pub mint: AccountInfo<'info>,
+
+    #[account(mut, seeds = [POOL_SEED.as_bytes(), mint.key().as_ref()], bump)]
+    pub pool_pda: Box<Account<'info, Pool>>,
+
+    #[account(seeds = [MINT_SEED.as_bytes(), pool_pda.key().as_ref()], bump)]
+    pub lp_token_mint_pda: Box<Account<'info, Mint>>,
+
+    /// CHECK: deserialized by hand, the legacy layout does not match Provider
+    #[account(mut, seeds = [PROVIDER_SEED.as_bytes(), pool_pda.key().as_ref(), signer.key().as_ref()], bump)]
+    pub legacy_provider: UncheckedAccount<'info>,
+
+    #[account(mut, token::authority = signer, token::mint = lp_token_mint_pda)]
+    pub signer_lp_ata: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = signer,
+        seeds = [PROVIDER_SEED.as_bytes(), pool_pda.key().as_ref(), signer.key().as_ref(), &[index]],
+        bump,
+        space = 8 + 32 + 1 + 8 + 8 + 8 + 1 + 8 + 8 + 8 + 8 + MAX_REWARD_STREAMS * 32,
+    )]
+    pub provider: Box<Account<'info, Provider>>,
+
+    #[account(
+        init,
+        payer = signer,
+        seeds = [POSITION_SEED.as_bytes(), provider.key().as_ref()],
+        bump,
+        token::mint = lp_token_mint_pda,
+        token::authority = signer,
+    )]
+    pub position_lp_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        seeds = [POSITION_REGISTRY_SEED.as_bytes(), pool_pda.key().as_ref(), signer.key().as_ref()],
+        bump,
+        space = 8 + 32 + 8,
+    )]
+    pub position_registry: Box<Account<'info, PositionRegistry>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
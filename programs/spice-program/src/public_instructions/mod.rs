@@ -1,9 +1,53 @@
 pub use increase_liquidity::*;
 pub use decrease_liquidity::*;
+pub use decrease_liquidity_into::*;
+pub use claim_protocol_fees::*;
+pub use quote_withdrawal::*;
 pub use harvest_yield::*;
 pub use swap::*;
+pub use create_dca_order::*;
+pub use execute_dca_order::*;
+pub use close_dca_order::*;
+pub use flash_borrow::*;
+pub use flash_repay::*;
+pub use sync_provider::*;
+pub use compound_yield::*;
+pub use set_keeper_settings::*;
+pub use keeper_compound::*;
+pub use keeper_harvest::*;
+pub use lock_liquidity::*;
+pub use unlock_liquidity::*;
+pub use claim_rewards::*;
+pub use fund_incentive::*;
+pub use refund_incentive::*;
+pub use close_provider::*;
+pub use transfer_position::*;
+pub use migrate_provider::*;
+pub use expire_boost::*;
 
 pub mod increase_liquidity;
 pub mod decrease_liquidity;
 pub mod harvest_yield;
-pub mod swap;
\ No newline at end of file
+pub mod swap;
+pub mod create_dca_order;
+pub mod execute_dca_order;
+pub mod close_dca_order;
+pub mod flash_borrow;
+pub mod flash_repay;
+pub mod sync_provider;
+pub mod compound_yield;
+pub mod set_keeper_settings;
+pub mod keeper_compound;
+pub mod keeper_harvest;
+pub mod lock_liquidity;
+pub mod unlock_liquidity;
+pub mod claim_rewards;
+pub mod fund_incentive;
+pub mod refund_incentive;
+pub mod close_provider;
+pub mod transfer_position;
+pub mod decrease_liquidity_into;
+pub mod claim_protocol_fees;
+pub mod quote_withdrawal;
+pub mod migrate_provider;
+pub mod expire_boost;
\ No newline at end of file
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::POOL_SEED, functions::{cover_deficit, quote_withdrawal as quote, WithdrawalQuote}, states::Pool};
+
+// Read-only, meant to be simulated by clients before decrease_liquidity or decrease_liquidity_into
+pub fn quote_withdrawal(ctx: Context<QuoteWithdrawalInstructionAccounts>, amount: u64) -> Result<WithdrawalQuote> {
+    let mut pool = ctx.accounts.pool_pda.clone().into_inner();
+    cover_deficit(&mut pool)?;
+
+    let withdrawal = quote(&pool, amount)?;
+
+    msg!("Withdrawal: {}", withdrawal.amount);
+    msg!("Withdrawal fee: {}", withdrawal.fee);
+
+    Ok(withdrawal)
+}
+
+#[derive(Accounts)]
+pub struct QuoteWithdrawalInstructionAccounts<'info> {
+    /// CHECK:
+    pub mint: AccountInfo<'info>,
+
+    #[account(seeds = [POOL_SEED.as_bytes(), mint.key().as_ref()], bump)]
+    pub pool_pda: Account<'info, Pool>,
+}
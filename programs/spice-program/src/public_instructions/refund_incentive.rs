@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token::{self, Mint, Token, TokenAccount}};
+
+use crate::{constants::{POOL_SEED, SPICE_SEED, TREASURY_SEED}, errors::SpiceError, functions::{refund_reward_stream, update_reward_streams}, states::{Pool, Settings}};
+
+// Permissionless, the refund always goes to the stream's funder
+pub fn refund_incentive(ctx: Context<RefundIncentiveInstructionAccounts>, slot: u8) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    update_reward_streams(&mut ctx.accounts.pool_pda, now)?;
+
+    let refund = refund_reward_stream(&mut ctx.accounts.pool_pda, slot as usize, now)?;
+    msg!("Refund: {}", refund);
+
+    // Transfer from Treasury to Funder
+    let seeds = &[SPICE_SEED.as_bytes(), TREASURY_SEED.as_bytes(), &[ctx.bumps.treasury]];
+    let signer = [&seeds[..]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.treasury_reward_ata.to_account_info(),
+                to: ctx.accounts.funder_reward_ata.to_account_info(),
+                authority: ctx.accounts.treasury.to_account_info(),
+            },
+            &signer,
+        ),
+        refund,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(slot: u8)]
+pub struct RefundIncentiveInstructionAccounts<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// CHECK:
+    pub mint: AccountInfo<'info>,
+
+    #[account(mut, seeds = [POOL_SEED.as_bytes(), mint.key().as_ref()], bump)]
+    pub pool_pda: Account<'info, Pool>,
+
+    /// CHECK: stream funder
+    #[account(constraint = pool_pda.reward_streams.get(slot as usize).is_some_and(|stream| stream.funder == funder.key()) @ SpiceError::InvalidRewardStream)]
+    pub funder: AccountInfo<'info>,
+
+    #[account(constraint = pool_pda.reward_streams.get(slot as usize).is_some_and(|stream| stream.mint == reward_mint.key()) @ SpiceError::InvalidRewardStream)]
+    pub reward_mint: Account<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = reward_mint,
+        associated_token::authority = funder,
+    )]
+    pub funder_reward_ata: Account<'info, TokenAccount>,
+
+    #[account(seeds = [SPICE_SEED.as_bytes(), TREASURY_SEED.as_bytes()], bump)]
+    pub treasury: Account<'info, Settings>,
+
+    #[account(mut, associated_token::mint = reward_mint, associated_token::authority = treasury)]
+    pub treasury_reward_ata: Account<'info, TokenAccount>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
\ No newline at end of file
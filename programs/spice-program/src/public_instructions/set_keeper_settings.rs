@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::{MAX_KEEPER_TIP, POOL_SEED, PROVIDER_SEED}, errors::SpiceError, states::{Pool, Provider}};
+
+pub fn set_keeper_settings(ctx: Context<SetKeeperSettingsInstructionAccounts>, index: u8, keeper_enabled: bool, keeper_tip: u64) -> Result<()> {
+    msg!("Position: {}", index);
+
+    if keeper_tip > MAX_KEEPER_TIP {
+        return Err(SpiceError::InvalidKeeperTip.into());
+    }
+
+    ctx.accounts.provider.keeper_enabled = keeper_enabled;
+    ctx.accounts.provider.keeper_tip = keeper_tip;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(index: u8)]
+pub struct SetKeeperSettingsInstructionAccounts<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// CHECK:
+    pub mint: AccountInfo<'info>,
+
+    #[account(seeds = [POOL_SEED.as_bytes(), mint.key().as_ref()], bump)]
+    pub pool_pda: Account<'info, Pool>,
+
+    #[account(mut, seeds = [PROVIDER_SEED.as_bytes(), pool_pda.key().as_ref(), signer.key().as_ref(), &[index]], bump)]
+    pub provider: Account<'info, Provider>,
+}
\ No newline at end of file
@@ -3,9 +3,9 @@ use anchor_spl::token::{Mint, Token, TokenAccount};
 use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
 
 use crate::{
-    constants::{POOL_SEED, SPICE_SCALE, SPICE_SEED, TREASURY_SEED},
+    constants::{POOL_SEED, SPICE_SEED, TREASURY_SEED},
     errors::SpiceError,
-    functions::{check_stoptap, dynamic_fee, net_amount_out, raw_amount_out, transfer_from_treasury, transfer_partner_fee, transfer_to_treasury},
+    functions::{check_pools, check_stoptap, quote_swap, settle_swap, transfer_from_treasury, transfer_partner_fee, transfer_to_treasury},
     states::{Pool, Settings},
 };
 
@@ -20,18 +20,12 @@ pub fn swap(
     let pool_a = &mut ctx.accounts.a_pool_pda;
     let pool_b = &mut ctx.accounts.b_pool_pda;
 
-    if pool_a.is_active == false {
-        return Err(SpiceError::PoolANotActive.into());
-    }
-    if pool_b.is_active == false {
-        return Err(SpiceError::PoolBNotActive.into());
-    }
-    if ctx.accounts.token_a_pyth_price_feed_account.key() != pool_a.pyth_price_feed_account {
-        return Err(SpiceError::InvalidPythAccount.into());
-    }
-    if ctx.accounts.token_b_pyth_price_feed_account.key() != pool_b.pyth_price_feed_account {
-        return Err(SpiceError::InvalidPythAccount.into());
-    }
+    check_pools(
+        pool_a,
+        pool_b,
+        ctx.accounts.token_a_pyth_price_feed_account.key(),
+        ctx.accounts.token_b_pyth_price_feed_account.key()
+    )?;
 
     let price_a = ctx.accounts.token_a_pyth_price_feed_account.price_message.price as u64;
     let price_b = ctx.accounts.token_b_pyth_price_feed_account.price_message.price as u64;
@@ -39,53 +33,43 @@ pub fn swap(
     msg!("Input price: {}", price_a);
     msg!("Output price: {}", price_b);
 
+    let epoch = Clock::get()?.epoch;
     let token_a_decimals = ctx.accounts.token_a_mint.decimals;
     let token_b_decimals = ctx.accounts.token_b_mint.decimals;
  
-    let raw_amount_out = raw_amount_out(
-        amount_in,
-        price_a,
-        price_b,
-        token_a_decimals,
-        token_b_decimals
-    )?;
-
-    let fee = dynamic_fee(
-        pool_b.base_fee, 
+    let quote = quote_swap(
+        pool_a,
+        pool_b,
         amount_in,
-        raw_amount_out as u64, 
-        pool_a.initial_liquidity, 
-        pool_a.current_liquidity, 
-        pool_b.initial_liquidity, 
-        pool_b.current_liquidity, 
+        (price_a, price_b),
+        (token_a_decimals, token_b_decimals),
+        partner_fee,
+        epoch
     )?;
 
-    let amount_out = net_amount_out(raw_amount_out, fee, partner_fee)?;
+    msg!("Input fee: {}", quote.input_fee);
+    msg!("Raw output: {}", quote.raw_amount_out);
+    msg!("Net output: {}", quote.amount_out);
+    msg!("Protocol fee: {}", quote.protocol_fee);
+    msg!("Partner fee: {}", quote.partner_fee);
+    msg!("Rebate: {}", quote.rebate);
 
-    msg!("Raw output: {}", raw_amount_out);
-    msg!("Net output: {}", amount_out.0);
-    msg!("Protocol fee: {}", amount_out.1);
-    msg!("Partner fee: {}", amount_out.2);
-
-    if amount_out.0 < min_amount_out {
+    if quote.amount_out < min_amount_out {
         return Err(SpiceError::HighSlippage.into());
     }
     
-    if amount_out.0 > pool_b.current_liquidity {
+    if quote.amount_out > pool_b.current_liquidity {
         return Err(SpiceError::InsufficientLiquidity.into());
     }
 
-    let output_amount_scale = amount_out.1 * SPICE_SCALE;
-    let protocol_income = output_amount_scale / ctx.accounts.treasury.income_distribution;
-    pool_b.protocol_income += protocol_income;
-
-    let protocol_income_unscale = protocol_income / SPICE_SCALE;
-
-    pool_a.current_liquidity += amount_in;
-    pool_b.current_liquidity -= raw_amount_out as u64 - protocol_income_unscale;
-
-    let provider_income = output_amount_scale - protocol_income;
-    pool_b.cumulative_yield += provider_income;
+    settle_swap(
+        pool_a,
+        pool_b,
+        amount_in,
+        &quote,
+        &ctx.accounts.treasury,
+        epoch
+    )?;
 
     transfer_to_treasury(
         ctx.accounts.signer.clone(), 
@@ -101,26 +85,24 @@ pub fn swap(
     let signer = &[&seeds[..]];
     
     transfer_from_treasury(
-        ctx.accounts.signer.clone(), 
+        ctx.accounts.signer.to_account_info(), 
         ctx.accounts.signer_b_ata.clone(), 
         ctx.accounts.treasury.clone(), 
         ctx.accounts.treasury_b_ata.clone(), 
         ctx.accounts.token_b_mint.key(), 
-        amount_out.0, 
+        quote.amount_out, 
         ctx.accounts.token_program.clone(), 
         signer)?;
     
     if partner_fee > 0 {
         let partner_fee_account = ctx.accounts.fee_account.as_ref().ok_or(SpiceError::MissingSPLAccount)?;
-        let partner_fee_output = amount_out.2;
-
         transfer_partner_fee(
             partner_fee_account.clone(), 
             Some(partner_fee_account.clone()), 
             ctx.accounts.treasury.clone(), 
             ctx.accounts.treasury_b_ata.clone(), 
             ctx.accounts.token_b_mint.key(), 
-            partner_fee_output, 
+            quote.partner_fee, 
             ctx.accounts.token_program.clone(), 
             signer)?;
     }
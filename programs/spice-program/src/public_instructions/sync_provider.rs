@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, TokenAccount};
+
+use crate::{constants::{MINT_SEED, POOL_SEED, POSITION_SEED, PROVIDER_SEED}, functions::{sync_lp_transfer, update_reward_streams}, states::{Pool, Provider}};
+
+// Permissionless checkpoint for both sides of an LP token transfer, settled before either balance moves.
+// Only existing positions are synced, LP can only be sent to a position its owner opened
+pub fn sync_provider(ctx: Context<SyncProviderInstructionAccounts>, index: u8, receiver_index: u8) -> Result<()> {
+    let sender_lp_balance = ctx.accounts.position_lp_account.amount;
+    let receiver_lp_balance = ctx.accounts.receiver_lp_account.amount;
+    msg!("Position: {} -> {}", index, receiver_index);
+    msg!("Sender LP balance: {} -> {}", ctx.accounts.provider.lp_balance, sender_lp_balance);
+    msg!("Receiver LP balance: {} -> {}", ctx.accounts.receiver_provider.lp_balance, receiver_lp_balance);
+
+    let now = Clock::get()?.unix_timestamp;
+    update_reward_streams(&mut ctx.accounts.pool_pda, now)?;
+    sync_lp_transfer(
+        &mut ctx.accounts.pool_pda,
+        &mut ctx.accounts.provider,
+        sender_lp_balance,
+        &mut ctx.accounts.receiver_provider,
+        receiver_lp_balance,
+        now,
+    )?;
+
+    msg!("Unsynced LP: {}", ctx.accounts.pool_pda.unsynced_lp);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(index: u8, receiver_index: u8)]
+pub struct SyncProviderInstructionAccounts<'info> {
+    pub signer: Signer<'info>,
+
+    /// CHECK: sending position owner
+    pub owner: AccountInfo<'info>,
+
+    /// CHECK: receiving position owner
+    pub receiver: AccountInfo<'info>,
+
+    /// CHECK:
+    pub mint: AccountInfo<'info>,
+
+    #[account(mut, seeds = [POOL_SEED.as_bytes(), mint.key().as_ref()], bump)]
+    pub pool_pda: Account<'info, Pool>,
+
+    #[account(seeds = [MINT_SEED.as_bytes(), pool_pda.key().as_ref()], bump)]
+    pub lp_token_mint_pda: Account<'info, Mint>,
+
+    #[account(mut, seeds = [PROVIDER_SEED.as_bytes(), pool_pda.key().as_ref(), owner.key().as_ref(), &[index]], bump)]
+    pub provider: Account<'info, Provider>,
+
+    #[account(seeds = [POSITION_SEED.as_bytes(), provider.key().as_ref()], bump, token::mint = lp_token_mint_pda, token::authority = owner)]
+    pub position_lp_account: Account<'info, TokenAccount>,
+
+    #[account(mut, seeds = [PROVIDER_SEED.as_bytes(), pool_pda.key().as_ref(), receiver.key().as_ref(), &[receiver_index]], bump)]
+    pub receiver_provider: Account<'info, Provider>,
+
+    #[account(seeds = [POSITION_SEED.as_bytes(), receiver_provider.key().as_ref()], bump, token::mint = lp_token_mint_pda, token::authority = receiver)]
+    pub receiver_lp_account: Account<'info, TokenAccount>,
+}
\ No newline at end of file
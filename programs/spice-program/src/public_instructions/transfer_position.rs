@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token::{self, Mint, Token, TokenAccount}};
+
+use crate::{constants::{MAX_REWARD_STREAMS, MINT_SEED, POOL_SEED, POSITION_REGISTRY_SEED, POSITION_SEED, PROVIDER_SEED}, errors::SpiceError, functions::{merge_position, register_position, sync_lp_balance, unregister_position, update_reward_streams}, states::{Pool, PositionRegistry, Provider}};
+
+pub fn transfer_position(ctx: Context<TransferPositionInstructionAccounts>, index: u8, destination_index: u8) -> Result<()> {
+    msg!("Position: {} -> {}", index, destination_index);
+
+    let now = Clock::get()?.unix_timestamp;
+    update_reward_streams(&mut ctx.accounts.pool_pda, now)?;
+
+    let lp_token_amount = ctx.accounts.position_lp_account.amount;
+    sync_lp_balance(&mut ctx.accounts.pool_pda, &mut ctx.accounts.provider, lp_token_amount, now)?;
+    sync_lp_balance(&mut ctx.accounts.pool_pda, &mut ctx.accounts.destination_provider, ctx.accounts.destination_lp_account.amount, now)?;
+
+    msg!("LP balance: {}", ctx.accounts.provider.lp_balance);
+    msg!("Pending claim: {}", ctx.accounts.provider.pending_claim);
+
+    merge_position(&mut ctx.accounts.provider, &mut ctx.accounts.destination_provider)?;
+    ctx.accounts.destination_provider.address = ctx.accounts.destination.key();
+    ctx.accounts.destination_provider.index = destination_index;
+
+    unregister_position(&mut ctx.accounts.position_registry, index)?;
+    register_position(&mut ctx.accounts.destination_registry, destination_index)?;
+    ctx.accounts.destination_registry.owner = ctx.accounts.destination.key();
+
+    // Move LP, locked LP stays in the Treasury escrow
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.position_lp_account.to_account_info(),
+                to: ctx.accounts.destination_lp_account.to_account_info(),
+                authority: ctx.accounts.signer.to_account_info(),
+            },
+        ),
+        lp_token_amount,
+    )?;
+
+    token::close_account(CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        token::CloseAccount {
+            account: ctx.accounts.position_lp_account.to_account_info(),
+            destination: ctx.accounts.signer.to_account_info(),
+            authority: ctx.accounts.signer.to_account_info(),
+        },
+    ))?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(index: u8, destination_index: u8)]
+pub struct TransferPositionInstructionAccounts<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    /// CHECK: new position owner
+    #[account(constraint = destination.key() != signer.key() @ SpiceError::InvalidSigner)]
+    pub destination: AccountInfo<'info>,
+
+    /// CHECK:
+    pub mint: AccountInfo<'info>,
+
+    #[account(mut, seeds = [POOL_SEED.as_bytes(), mint.key().as_ref()], bump)]
+    pub pool_pda: Account<'info, Pool>,
+
+    #[account(seeds = [MINT_SEED.as_bytes(), pool_pda.key().as_ref()], bump)]
+    pub lp_token_mint_pda: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        close = signer,
+        seeds = [PROVIDER_SEED.as_bytes(), pool_pda.key().as_ref(), signer.key().as_ref(), &[index]],
+        bump,
+    )]
+    pub provider: Account<'info, Provider>,
+
+    #[account(mut, seeds = [POSITION_SEED.as_bytes(), provider.key().as_ref()], bump, token::mint = lp_token_mint_pda, token::authority = signer)]
+    pub position_lp_account: Account<'info, TokenAccount>,
+
+    #[account(mut, seeds = [POSITION_REGISTRY_SEED.as_bytes(), pool_pda.key().as_ref(), signer.key().as_ref()], bump)]
+    pub position_registry: Account<'info, PositionRegistry>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        seeds = [PROVIDER_SEED.as_bytes(), pool_pda.key().as_ref(), destination.key().as_ref(), &[destination_index]],
+        bump,
+        space = 8 + 32 + 1 + 8 + 8 + 8 + 1 + 8 + 8 + 8 + 8 + MAX_REWARD_STREAMS * 32,
+    )]
+    pub destination_provider: Account<'info, Provider>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        seeds = [POSITION_SEED.as_bytes(), destination_provider.key().as_ref()],
+        bump,
+        token::mint = lp_token_mint_pda,
+        token::authority = destination,
+    )]
+    pub destination_lp_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        seeds = [POSITION_REGISTRY_SEED.as_bytes(), pool_pda.key().as_ref(), destination.key().as_ref()],
+        bump,
+        space = 8 + 32 + 8,
+    )]
+    pub destination_registry: Account<'info, PositionRegistry>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
\ No newline at end of file
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(Default)]
+pub struct DcaOrder {
+    pub owner: Pubkey,
+    pub id: u64,
+    pub input_mint: Pubkey,
+    pub output_mint: Pubkey,
+    pub amount_per_slice: u64,
+    pub remaining_amount: u64,
+    pub slices: u64,
+    pub executed_slices: u64,
+    pub min_interval: i64, // seconds
+    pub last_execution: i64,
+    pub min_amount_out: u64 // per slice
+}
\ No newline at end of file
@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_FEE_RECIPIENTS;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct FeeSplit {
+    pub recipients: [FeeRecipient; MAX_FEE_RECIPIENTS],
+    pub count: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct FeeRecipient {
+    pub address: Pubkey,
+    pub weight: u64, // scale 1:FEE_SCALE
+}
@@ -1,7 +1,13 @@
 pub use pool::*;
 pub use provider::*;
 pub use settings::*;
+pub use dca_order::*;
+pub use position_registry::*;
+pub use fee_split::*;
 
 pub mod pool;
 pub mod provider;
-pub mod settings;
\ No newline at end of file
+pub mod settings;
+pub mod dca_order;
+pub mod position_registry;
+pub mod fee_split;
\ No newline at end of file
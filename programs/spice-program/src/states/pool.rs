@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::{MAX_FEE_BREAKPOINTS, MAX_FEE_SHARES, MAX_REWARD_STREAMS};
+
 #[account]
+#[derive(Default)]
 pub struct Pool {
     pub is_active: bool,
     pub base_fee: u64, // scale 1:FEE_SCALE
@@ -10,5 +13,92 @@ pub struct Pool {
     pub initial_liquidity: u64,
     pub cumulative_yield: u64, // scale 1:SPICE_SCALE
     pub current_liquidity: u64,
+    pub protocol_income: u64,
+    pub flash_loan_fee: u64, // scale 1:FEE_SCALE
+    pub flash_loan_amount: u64,
+    pub imbalance_fee: DynamicFeeParams,
+    pub regular_fee: DynamicFeeParams,
+    pub fee_curve: FeeCurve,
+    pub rebate_rate: u64, // scale 1:FEE_SCALE
+    pub max_rebate_per_trade: u64,
+    pub max_rebate_per_epoch: u64,
+    pub rebate_epoch: u64,
+    pub epoch_rebates: u64,
+    pub lp_supply: u64,
+    pub total_lp_boost: u64,
+    pub unsynced_lp: u64, // LP released by a position and not yet credited to another
+    pub reward_streams: [RewardStream; MAX_REWARD_STREAMS],
+    pub reward_stream_nonce: u64,
+    pub deficit_coverage: u64, // share of protocol income, scale 1:FEE_SCALE
+    pub fee_shares: [FeeShare; MAX_FEE_SHARES],
+    pub protocol_share: Option<u64>, // overrides Settings, scale 1:FEE_SCALE
+    pub withdrawal_fee: WithdrawalFeeParams,
+    pub version: u8,
+}
+
+// Layout written before the pool grew past its liquidity counters
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyPool {
+    pub is_active: bool,
+    pub base_fee: u64,
+    pub mint: Pubkey,
+    pub pyth_price_feed_account: Pubkey,
+    pub lp_token_mint: Pubkey,
+    pub initial_liquidity: u64,
+    pub cumulative_yield: u64,
+    pub current_liquidity: u64,
     pub protocol_income: u64
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct DynamicFeeParams {
+    pub max_fee: u64, // scale 1:FEE_SCALE
+    pub steepness: u64 // scale 1:SPICE_SCALE
+}
+
+// Charged on withdrawals while the pool is below its target
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct WithdrawalFeeParams {
+    pub max_fee: u64, // scale 1:FEE_SCALE
+    pub depth_steepness: u64, // fee per deficit depth, scale 1:SPICE_SCALE
+    pub size_steepness: u64 // boost per withdrawn share of the pool, scale 1:SPICE_SCALE
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct FeeBreakpoint {
+    pub usage: u64, // steepness * liquidity usage, scale 1:SPICE_SCALE
+    pub fee: u64 // share of max_fee, scale 1:FEE_SCALE
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub enum FeeCurve {
+    #[default]
+    Exponential,
+    Linear,
+    PiecewiseLinear {
+        breakpoints: [FeeBreakpoint; MAX_FEE_BREAKPOINTS],
+        count: u8,
+    },
+}
+
+// An empty slot has a default mint
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct RewardStream {
+    pub id: u64,
+    pub mint: Pubkey,
+    pub rate: u64, // reward tokens per second
+    pub start: i64,
+    pub end: i64,
+    pub last_update: i64,
+    pub reward_per_share: u128, // scale 1:FIXED_POINT_SCALE
+    pub distributed: u64,
+    pub funder: Pubkey,
+    pub funding: u64,
+}
+
+// Protocol income split off to a recipient, an empty slot has no amount
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct FeeShare {
+    pub recipient: Pubkey,
+    pub amount: u64,
 }
\ No newline at end of file
@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+
+// Bit i is set while the owner holds a position with index i
+#[account]
+#[derive(Default)]
+pub struct PositionRegistry {
+    pub owner: Pubkey,
+    pub positions: u64
+}
\ No newline at end of file
@@ -1,9 +1,35 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::MAX_REWARD_STREAMS;
+
 #[account]
+#[derive(Default)]
 pub struct Provider {
+    pub address: Pubkey,
+    pub index: u8,
+    pub lp_balance: u64,
+    pub last_cumulative_yield: u64,
+    pub pending_claim: u64,
+    pub keeper_enabled: bool,
+    pub keeper_tip: u64, // scale 1:BPS_SCALE
+    pub locked_lp: u64,
+    pub lock_end: i64,
+    pub lp_boost: u64,
+    pub reward_debts: [RewardDebt; MAX_REWARD_STREAMS],
+}
+
+// Layout written before positions were indexed, seeded without an index
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyProvider {
     pub address: Pubkey,
     pub lp_balance: u64,
     pub last_cumulative_yield: u64,
     pub pending_claim: u64
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct RewardDebt {
+    pub stream_id: u64,
+    pub reward_per_share: u128, // scale 1:FIXED_POINT_SCALE
+    pub pending: u64,
 }
\ No newline at end of file
@@ -1,8 +1,21 @@
 use anchor_lang::prelude::*;
 
+use crate::states::FeeSplit;
+
 #[account]
+#[derive(Default)]
 pub struct Settings {
+    pub admin: Pubkey,
+    pub protocol_share: u64, // scale 1:FEE_SCALE
+    pub stoptap: bool,
+    pub version: u8,
+    pub fee_split: FeeSplit,
+}
+
+// Layout written before the protocol share replaced the income divisor
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LegacySettings {
     pub admin: Pubkey,
     pub income_distribution: u64,
     pub stoptap: bool
-}
\ No newline at end of file
+}
@@ -0,0 +1,29 @@
+#![allow(dead_code)]
+
+use spice_program::{
+    constants::SPICE_SCALE,
+    states::{DynamicFeeParams, Pool, Settings, WithdrawalFeeParams},
+};
+
+// An active pool with the default swap fees, its LP supply matching the target
+pub fn pool(initial_liquidity: u64, current_liquidity: u64) -> Pool {
+    Pool {
+        is_active: true,
+        base_fee: 10,
+        initial_liquidity,
+        current_liquidity,
+        lp_supply: initial_liquidity,
+        imbalance_fee: DynamicFeeParams { max_fee: 10_000, steepness: 5 * SPICE_SCALE },
+        regular_fee: DynamicFeeParams { max_fee: 1_000, steepness: SPICE_SCALE },
+        ..Default::default()
+    }
+}
+
+pub fn settings(protocol_share: u64) -> Settings {
+    Settings { protocol_share, ..Default::default() }
+}
+
+// The fee a new pool starts with
+pub fn withdrawal_fee_params() -> WithdrawalFeeParams {
+    WithdrawalFeeParams { max_fee: 5_000, depth_steepness: SPICE_SCALE / 10, size_steepness: SPICE_SCALE }
+}
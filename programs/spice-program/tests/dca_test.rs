@@ -0,0 +1,83 @@
+use spice_program::{
+    errors::SpiceError,
+    functions::{cancel_dca_order, check_dca_order, next_dca_slice, record_dca_slice},
+    states::DcaOrder,
+};
+
+fn order(amount: u64, slices: u64, min_interval: i64, min_amount_out: u64) -> DcaOrder {
+    DcaOrder {
+        amount_per_slice: check_dca_order(amount, slices, min_interval).unwrap(),
+        remaining_amount: amount,
+        slices,
+        min_interval,
+        min_amount_out,
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod dca {
+
+    use super::*;
+
+    #[test]
+    fn rejecting_invalid_orders() {
+        assert!(matches!(check_dca_order(1_000, 0, 60), Err(SpiceError::InvalidSliceCount)));
+        assert!(matches!(check_dca_order(2, 3, 60), Err(SpiceError::InvalidSliceCount)));
+        assert!(matches!(check_dca_order(1_000, 3, -1), Err(SpiceError::InvalidSliceCount)));
+        assert_eq!(check_dca_order(1_000, 3, 0).unwrap(), 333);
+    }
+
+    #[test]
+    fn final_slice_takes_remainder() {
+        let mut dca_order = order(1_000, 3, 60, 0);
+
+        for (now, expected) in [(100, 333), (160, 333), (220, 334)] {
+            let amount_in = next_dca_slice(&dca_order, now).unwrap();
+            assert_eq!(amount_in, expected);
+            record_dca_slice(&mut dca_order, amount_in, 1, now).unwrap();
+        }
+
+        assert_eq!(dca_order.remaining_amount, 0);
+        assert_eq!(dca_order.executed_slices, 3);
+        assert!(matches!(next_dca_slice(&dca_order, 1_000), Err(SpiceError::DcaOrderCompleted)));
+    }
+
+    #[test]
+    fn rejecting_early_execution() {
+        let mut dca_order = order(1_000, 4, 60, 0);
+
+        // The first slice runs right away
+        let amount_in = next_dca_slice(&dca_order, 100).unwrap();
+        record_dca_slice(&mut dca_order, amount_in, 1, 100).unwrap();
+
+        assert!(matches!(next_dca_slice(&dca_order, 159), Err(SpiceError::DcaIntervalNotElapsed)));
+        assert_eq!(next_dca_slice(&dca_order, 160).unwrap(), 250);
+    }
+
+    #[test]
+    fn slippage_failure_leaves_order_untouched() {
+        let mut dca_order = order(1_000, 2, 60, 490);
+
+        let amount_in = next_dca_slice(&dca_order, 100).unwrap();
+        assert!(matches!(record_dca_slice(&mut dca_order, amount_in, 489, 100), Err(SpiceError::HighSlippage)));
+        assert_eq!(dca_order.remaining_amount, 1_000);
+        assert_eq!(dca_order.executed_slices, 0);
+        assert_eq!(dca_order.last_execution, 0);
+
+        record_dca_slice(&mut dca_order, amount_in, 490, 100).unwrap();
+        assert_eq!(dca_order.remaining_amount, 500);
+        assert_eq!(dca_order.executed_slices, 1);
+        assert_eq!(dca_order.last_execution, 100);
+    }
+
+    #[test]
+    fn cancel_refunds_remaining_input() {
+        let mut dca_order = order(1_000, 3, 60, 0);
+        let amount_in = next_dca_slice(&dca_order, 100).unwrap();
+        record_dca_slice(&mut dca_order, amount_in, 1, 100).unwrap();
+
+        assert_eq!(cancel_dca_order(&mut dca_order), 667);
+        assert_eq!(cancel_dca_order(&mut dca_order), 0);
+    }
+}
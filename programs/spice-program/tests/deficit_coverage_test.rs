@@ -0,0 +1,94 @@
+mod common;
+
+use common::{pool, settings};
+use spice_program::{
+    functions::{compound_provider, cover_deficit, quote_swap, settle_swap},
+    states::{Pool, Provider},
+};
+
+fn covered_pool(initial_liquidity: u64, current_liquidity: u64, protocol_income: u64, deficit_coverage: u64) -> Pool {
+    Pool { protocol_income, deficit_coverage, ..pool(initial_liquidity, current_liquidity) }
+}
+
+#[cfg(test)]
+mod deficit_coverage {
+
+    use super::*;
+
+    #[test]
+    fn no_coverage_at_target() {
+        let mut balanced = covered_pool(1_000_000, 1_000_000, 5_000, 100_000);
+        let mut surplus = covered_pool(1_000_000, 1_100_000, 5_000, 100_000);
+
+        assert_eq!(cover_deficit(&mut balanced).unwrap(), 0);
+        assert_eq!(cover_deficit(&mut surplus).unwrap(), 0);
+        assert_eq!(balanced.protocol_income, 5_000);
+        assert_eq!(surplus.current_liquidity, 1_100_000);
+    }
+
+    #[test]
+    fn covering_deficit_up_to_target() {
+        let mut pool_a = covered_pool(1_000_000, 998_000, 5_000, 100_000);
+
+        assert_eq!(cover_deficit(&mut pool_a).unwrap(), 2_000);
+        assert_eq!(pool_a.current_liquidity, 1_000_000);
+        assert_eq!(pool_a.protocol_income, 3_000);
+    }
+
+    #[test]
+    fn covering_with_configured_share() {
+        let mut pool_a = covered_pool(1_000_000, 900_000, 5_000, 40_000);
+
+        assert_eq!(cover_deficit(&mut pool_a).unwrap(), 2_000);
+        assert_eq!(pool_a.current_liquidity, 902_000);
+        assert_eq!(pool_a.protocol_income, 3_000);
+
+        let mut disabled = covered_pool(1_000_000, 900_000, 5_000, 0);
+        assert_eq!(cover_deficit(&mut disabled).unwrap(), 0);
+    }
+
+    #[test]
+    fn no_coverage_for_outstanding_flash_loan() {
+        let mut borrowed = covered_pool(1_000_000, 400_000, 5_000, 100_000);
+        borrowed.flash_loan_amount = 600_000;
+
+        // Only the part of the value below target is a deficit
+        assert_eq!(cover_deficit(&mut borrowed).unwrap(), 0);
+        assert_eq!(borrowed.protocol_income, 5_000);
+
+        borrowed.current_liquidity = 398_000;
+        assert_eq!(cover_deficit(&mut borrowed).unwrap(), 2_000);
+        assert_eq!(borrowed.current_liquidity, 400_000);
+        assert_eq!(borrowed.protocol_income, 3_000);
+
+        // Repaid, the pool is back at its target
+        borrowed.current_liquidity += borrowed.flash_loan_amount;
+        borrowed.flash_loan_amount = 0;
+        assert_eq!(cover_deficit(&mut borrowed).unwrap(), 0);
+        assert_eq!(borrowed.current_liquidity, 1_000_000);
+    }
+
+    #[test]
+    fn covering_deficit_after_swap() {
+        let mut pool_a = covered_pool(1_000_000, 1_000_000, 0, 100_000);
+        let mut pool_b = covered_pool(1_000_000, 1_000_000, 50, 100_000);
+
+        let quote = quote_swap(&pool_a, &pool_b, 10_000, (100, 100), (6, 6), 0, 0).unwrap();
+        settle_swap(&mut pool_a, &mut pool_b, 10_000, &quote, &settings(50_000), 0).unwrap();
+
+        let protocol_income = 50 + quote.protocol_fee / 2;
+        assert_eq!(pool_b.current_liquidity, 1_000_000 - quote.raw_amount_out + protocol_income);
+        assert_eq!(pool_b.protocol_income, 0);
+    }
+
+    #[test]
+    fn covering_deficit_before_deposit() {
+        let mut pool_a = covered_pool(1_000, 900, 100, 100_000);
+        let mut provider = Provider { lp_balance: 500, pending_claim: 100, ..Default::default() };
+
+        // Priced at the covered value, the depositor does not share the covered income
+        assert_eq!(compound_provider(&mut pool_a, &mut provider, 0).unwrap(), 100);
+        assert_eq!(pool_a.protocol_income, 0);
+        assert_eq!(pool_a.current_liquidity, 1_100);
+    }
+}
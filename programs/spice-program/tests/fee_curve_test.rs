@@ -0,0 +1,88 @@
+use proptest::prelude::*;
+use spice_program::{
+    constants::{MAX_DYNAMIC_FEE, MAX_FEE_STEEPNESS, SPICE_SCALE},
+    functions::curve_fee,
+    states::{DynamicFeeParams, FeeCurve},
+};
+
+fn f64_curve_fee(params: &DynamicFeeParams, amount_out: u64, liquidity: u64) -> u64 {
+    let steepness = params.steepness as f64 / SPICE_SCALE as f64;
+    let liquidity_usage_ratio = amount_out as f64 / liquidity as f64;
+    (params.max_fee as f64 * (1.0 - (-steepness * liquidity_usage_ratio).exp())).round() as u64
+}
+
+#[cfg(test)]
+mod fee_curve {
+
+    use super::*;
+
+    #[test]
+    fn matching_default_curves() {
+        let imbalance = DynamicFeeParams { max_fee: 10_000, steepness: 5 * SPICE_SCALE };
+        let regular = DynamicFeeParams { max_fee: 1_000, steepness: SPICE_SCALE };
+        let liquidity = 10_000_000_000;
+
+        for step in 0..=20_000u64 {
+            let amount_out = liquidity / 1_000 * step / 10;
+            for params in [&imbalance, &regular] {
+                let fixed = curve_fee(&FeeCurve::Exponential, params, amount_out, liquidity).unwrap();
+                let float = f64_curve_fee(params, amount_out, liquidity);
+                assert!(fixed.abs_diff(float) <= 1, "ratio {} fixed {} f64 {}", step, fixed, float);
+            }
+        }
+    }
+
+    #[test]
+    fn saturating_at_max_fee() {
+        let params = DynamicFeeParams { max_fee: 10_000, steepness: 5 * SPICE_SCALE };
+
+        assert_eq!(curve_fee(&FeeCurve::Exponential, &params, 0, 1_000).unwrap(), 0);
+        assert_eq!(curve_fee(&FeeCurve::Exponential, &params, 1_000, 1_000).unwrap(), 9_933);
+        assert_eq!(curve_fee(&FeeCurve::Exponential, &params, u64::MAX, 1).unwrap(), 10_000);
+    }
+
+    proptest! {
+        #[test]
+        fn matching_f64_curve(
+            max_fee in 0..=MAX_DYNAMIC_FEE,
+            steepness in 1..=MAX_FEE_STEEPNESS,
+            liquidity in 1..=u64::MAX / 2,
+            ratio in 0.0..10.0f64,
+        ) {
+            let params = DynamicFeeParams { max_fee, steepness };
+            let amount_out = (liquidity as f64 * ratio).min(u64::MAX as f64) as u64;
+
+            let fixed = curve_fee(&FeeCurve::Exponential, &params, amount_out, liquidity).unwrap();
+            let float = f64_curve_fee(&params, amount_out, liquidity);
+            prop_assert!(fixed.abs_diff(float) <= 1, "fixed {} f64 {}", fixed, float);
+        }
+
+        #[test]
+        fn matching_f64_curve_small_pools(
+            max_fee in 0..=MAX_DYNAMIC_FEE,
+            steepness in 1..=MAX_FEE_STEEPNESS,
+            liquidity in 1..=1_000_000u64,
+            amount_out in 0..=10_000_000u64,
+        ) {
+            let params = DynamicFeeParams { max_fee, steepness };
+
+            let fixed = curve_fee(&FeeCurve::Exponential, &params, amount_out, liquidity).unwrap();
+            let float = f64_curve_fee(&params, amount_out, liquidity);
+            prop_assert!(fixed.abs_diff(float) <= 1, "fixed {} f64 {}", fixed, float);
+        }
+
+        #[test]
+        fn monotonic_in_amount_out(
+            steepness in 1..=MAX_FEE_STEEPNESS,
+            liquidity in 1..=1_000_000_000_000u64,
+            amount_out in 0..=1_000_000_000_000u64,
+        ) {
+            let params = DynamicFeeParams { max_fee: MAX_DYNAMIC_FEE, steepness };
+
+            let fee = curve_fee(&FeeCurve::Exponential, &params, amount_out, liquidity).unwrap();
+            let next_fee = curve_fee(&FeeCurve::Exponential, &params, amount_out + 1, liquidity).unwrap();
+            prop_assert!(next_fee >= fee);
+            prop_assert!(next_fee <= MAX_DYNAMIC_FEE);
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,80 @@
+use spice_program::{
+    constants::{FEE_SCALE, MAX_FEE_BREAKPOINTS, SPICE_SCALE},
+    functions::{check_fee_curve, curve_fee},
+    states::{DynamicFeeParams, FeeBreakpoint, FeeCurve},
+};
+
+fn piecewise(points: &[(u64, u64)]) -> FeeCurve {
+    let mut breakpoints = [FeeBreakpoint::default(); MAX_FEE_BREAKPOINTS];
+    for (breakpoint, (usage, fee)) in breakpoints.iter_mut().zip(points) {
+        *breakpoint = FeeBreakpoint { usage: *usage, fee: *fee };
+    }
+
+    FeeCurve::PiecewiseLinear { breakpoints, count: points.len() as u8 }
+}
+
+#[cfg(test)]
+mod fee_model {
+
+    use super::*;
+
+    const PARAMS: DynamicFeeParams = DynamicFeeParams { max_fee: 10_000, steepness: SPICE_SCALE };
+
+    #[test]
+    fn exponential_curve() {
+        let curve = FeeCurve::Exponential;
+
+        assert_eq!(curve_fee(&curve, &PARAMS, 0, 1_000).unwrap(), 0);
+        assert_eq!(curve_fee(&curve, &PARAMS, 500, 1_000).unwrap(), 3_935);
+        assert_eq!(curve_fee(&curve, &PARAMS, 1_000, 1_000).unwrap(), 6_321);
+        assert_eq!(curve_fee(&curve, &PARAMS, 10_000, 1_000).unwrap(), 10_000);
+    }
+
+    #[test]
+    fn linear_curve() {
+        let curve = FeeCurve::Linear;
+
+        assert_eq!(curve_fee(&curve, &PARAMS, 0, 1_000).unwrap(), 0);
+        assert_eq!(curve_fee(&curve, &PARAMS, 250, 1_000).unwrap(), 2_500);
+        assert_eq!(curve_fee(&curve, &PARAMS, 1_000, 1_000).unwrap(), 10_000);
+        assert_eq!(curve_fee(&curve, &PARAMS, 5_000, 1_000).unwrap(), 10_000);
+
+        let steep = DynamicFeeParams { max_fee: 10_000, steepness: 4 * SPICE_SCALE };
+        assert_eq!(curve_fee(&curve, &steep, 100, 1_000).unwrap(), 4_000);
+    }
+
+    #[test]
+    fn piecewise_linear_curve() {
+        // 10% usage -> 5% of max fee, 50% usage -> 100% of max fee
+        let curve = piecewise(&[(100, 5_000), (500, FEE_SCALE as u64)]);
+
+        assert_eq!(curve_fee(&curve, &PARAMS, 0, 1_000).unwrap(), 0);
+        assert_eq!(curve_fee(&curve, &PARAMS, 50, 1_000).unwrap(), 250);
+        assert_eq!(curve_fee(&curve, &PARAMS, 100, 1_000).unwrap(), 500);
+        assert_eq!(curve_fee(&curve, &PARAMS, 300, 1_000).unwrap(), 5_250);
+        assert_eq!(curve_fee(&curve, &PARAMS, 500, 1_000).unwrap(), 10_000);
+        assert_eq!(curve_fee(&curve, &PARAMS, 900, 1_000).unwrap(), 10_000);
+    }
+
+    #[test]
+    fn piecewise_linear_flat_after_last_breakpoint() {
+        let curve = piecewise(&[(200, 40_000)]);
+
+        assert_eq!(curve_fee(&curve, &PARAMS, 100, 1_000).unwrap(), 2_000);
+        assert_eq!(curve_fee(&curve, &PARAMS, 200, 1_000).unwrap(), 4_000);
+        assert_eq!(curve_fee(&curve, &PARAMS, 1_000, 1_000).unwrap(), 4_000);
+    }
+
+    #[test]
+    fn validating_piecewise_breakpoints() {
+        assert!(check_fee_curve(&piecewise(&[(100, 5_000), (500, 100_000)])).is_ok());
+        assert!(check_fee_curve(&FeeCurve::Exponential).is_ok());
+        assert!(check_fee_curve(&FeeCurve::Linear).is_ok());
+
+        assert!(check_fee_curve(&piecewise(&[])).is_err());
+        assert!(check_fee_curve(&piecewise(&[(0, 5_000)])).is_err());
+        assert!(check_fee_curve(&piecewise(&[(500, 5_000), (100, 10_000)])).is_err());
+        assert!(check_fee_curve(&piecewise(&[(100, 5_000), (500, 1_000)])).is_err());
+        assert!(check_fee_curve(&piecewise(&[(100, 100_001)])).is_err());
+    }
+}
\ No newline at end of file
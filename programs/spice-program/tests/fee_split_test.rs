@@ -0,0 +1,119 @@
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use common::pool;
+use spice_program::{
+    constants::{MAX_FEE_RECIPIENTS, MAX_FEE_SHARES},
+    errors::SpiceError,
+    functions::{check_fee_split, distribute_fee, take_fee_share, take_protocol_income},
+    states::{FeeRecipient, FeeSplit, Settings},
+};
+
+fn recipient(address: Pubkey, weight: u64) -> FeeRecipient {
+    FeeRecipient { address, weight }
+}
+
+fn settings(recipients: &[FeeRecipient]) -> Settings {
+    let mut fee_split = FeeSplit { count: recipients.len() as u8, ..Default::default() };
+    fee_split.recipients[..recipients.len()].copy_from_slice(recipients);
+    Settings { protocol_share: 100_000, fee_split, ..Default::default() }
+}
+
+#[cfg(test)]
+mod fee_split {
+
+    use super::*;
+
+    #[test]
+    fn validating_weights() {
+        let (insurance, team) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+        assert!(check_fee_split(&[recipient(insurance, 60_000), recipient(team, 40_000)]).is_ok());
+        assert!(check_fee_split(&[recipient(insurance, 60_000), recipient(team, 30_000)]).is_ok());
+        assert!(check_fee_split(&[recipient(insurance, 100_000)]).is_ok());
+
+        assert!(matches!(check_fee_split(&[]), Err(SpiceError::InvalidFeeSplit)));
+        assert!(matches!(check_fee_split(&[recipient(insurance, 60_000), recipient(team, 40_001)]), Err(SpiceError::InvalidFeeSplit)));
+        assert!(matches!(check_fee_split(&[recipient(insurance, 100_000), recipient(team, 0)]), Err(SpiceError::InvalidFeeSplit)));
+        assert!(matches!(check_fee_split(&[recipient(insurance, 50_000), recipient(insurance, 50_000)]), Err(SpiceError::InvalidFeeSplit)));
+        assert!(matches!(check_fee_split(&[recipient(Pubkey::default(), 100_000)]), Err(SpiceError::InvalidFeeSplit)));
+
+        let too_many = vec![recipient(Pubkey::new_unique(), 20_000); MAX_FEE_RECIPIENTS + 1];
+        assert!(matches!(check_fee_split(&too_many), Err(SpiceError::InvalidFeeSplit)));
+    }
+
+    #[test]
+    fn splitting_on_accrual() {
+        let (insurance, buyback, team) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let split = settings(&[recipient(insurance, 50_000), recipient(buyback, 30_000), recipient(team, 20_000)]);
+        let mut pool_a = pool(1_000_000, 1_000_000);
+
+        distribute_fee(&mut pool_a, 1_001, &split).unwrap();
+        assert_eq!(pool_a.protocol_income, 1);
+
+        // Shares accrue until claimed
+        distribute_fee(&mut pool_a, 1_999, &split).unwrap();
+
+        assert_eq!(take_fee_share(&mut pool_a, insurance).unwrap(), 1_499);
+        assert_eq!(take_fee_share(&mut pool_a, buyback).unwrap(), 899);
+        assert_eq!(take_fee_share(&mut pool_a, team).unwrap(), 599);
+        assert!(matches!(take_fee_share(&mut pool_a, team), Err(SpiceError::NoFeeShare)));
+        assert_eq!(pool_a.protocol_income, 3);
+    }
+
+    #[test]
+    fn keeping_unassigned_weight_with_the_pool() {
+        let team = Pubkey::new_unique();
+        let mut pool_a = pool(1_000_000, 1_000_000);
+
+        distribute_fee(&mut pool_a, 1_000, &settings(&[recipient(team, 80_000)])).unwrap();
+
+        assert_eq!(pool_a.protocol_income, 200);
+        assert_eq!(take_protocol_income(&mut pool_a, None).unwrap(), 200);
+        assert_eq!(take_fee_share(&mut pool_a, team).unwrap(), 800);
+    }
+
+    #[test]
+    fn no_split_in_deficit() {
+        let team = Pubkey::new_unique();
+        let mut pool_a = pool(1_000_000, 999_000);
+
+        distribute_fee(&mut pool_a, 5_000, &settings(&[recipient(team, 100_000)])).unwrap();
+
+        assert_eq!(pool_a.protocol_income, 5_000);
+        assert!(matches!(take_fee_share(&mut pool_a, team), Err(SpiceError::NoFeeShare)));
+    }
+
+    #[test]
+    fn changing_weights_is_not_retroactive() {
+        let (team, reserve) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut pool_a = pool(1_000_000, 1_000_000);
+
+        distribute_fee(&mut pool_a, 1_000, &settings(&[recipient(team, 100_000)])).unwrap();
+        distribute_fee(&mut pool_a, 400, &settings(&[recipient(reserve, 100_000)])).unwrap();
+
+        assert_eq!(take_fee_share(&mut pool_a, team).unwrap(), 1_000);
+        assert_eq!(take_fee_share(&mut pool_a, reserve).unwrap(), 400);
+        assert_eq!(pool_a.protocol_income, 0);
+    }
+
+    #[test]
+    fn running_out_of_share_slots() {
+        let mut pool_a = pool(1_000_000, 1_000_000);
+
+        let recipients: Vec<Pubkey> = (0..MAX_FEE_SHARES).map(|_| Pubkey::new_unique()).collect();
+        for address in &recipients {
+            distribute_fee(&mut pool_a, 100, &settings(&[recipient(*address, 100_000)])).unwrap();
+        }
+
+        // The trade still settles, the late recipient's share stays with the pool
+        let late = Pubkey::new_unique();
+        distribute_fee(&mut pool_a, 100, &settings(&[recipient(late, 100_000)])).unwrap();
+        assert_eq!(pool_a.protocol_income, 100);
+
+        // Claiming never depends on free slots
+        assert_eq!(take_fee_share(&mut pool_a, recipients[0]).unwrap(), 100);
+        distribute_fee(&mut pool_a, 100, &settings(&[recipient(late, 100_000)])).unwrap();
+        assert_eq!(take_fee_share(&mut pool_a, late).unwrap(), 100);
+    }
+}
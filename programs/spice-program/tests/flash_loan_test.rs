@@ -0,0 +1,76 @@
+mod common;
+
+use anchor_lang::{prelude::Pubkey, solana_program::instruction::{AccountMeta, Instruction}, Discriminator};
+use common::pool;
+use spice_program::{
+    errors::SpiceError,
+    functions::{check_flash_repay, dynamic_fee, flash_loan_fee, FLASH_REPAY_POOL_INDEX},
+    instruction::{FlashRepay, Swap},
+};
+
+fn instruction(program_id: Pubkey, discriminator: &[u8], pool: Pubkey) -> Instruction {
+    let mut accounts = vec![AccountMeta::new_readonly(Pubkey::new_unique(), false); FLASH_REPAY_POOL_INDEX + 2];
+    accounts[FLASH_REPAY_POOL_INDEX] = AccountMeta::new(pool, false);
+
+    Instruction { program_id, accounts, data: discriminator.to_vec() }
+}
+
+#[cfg(test)]
+mod flash_loan {
+
+    use super::*;
+
+    #[test]
+    fn finding_repay_later_in_transaction() {
+        let pool_a = Pubkey::new_unique();
+        let instructions = vec![
+            instruction(spice_program::ID, &Swap::DISCRIMINATOR, pool_a),
+            instruction(spice_program::ID, &FlashRepay::DISCRIMINATOR, pool_a),
+        ];
+
+        assert!(check_flash_repay(instructions.into_iter(), &pool_a).is_ok());
+    }
+
+    #[test]
+    fn rejecting_missing_repay() {
+        let pool_a = Pubkey::new_unique();
+
+        assert!(matches!(check_flash_repay(Vec::new().into_iter(), &pool_a), Err(SpiceError::FlashLoanNotRepaid)));
+
+        // Same data sent to another program doesn't repay
+        let foreign = vec![instruction(Pubkey::new_unique(), &FlashRepay::DISCRIMINATOR, pool_a)];
+        assert!(matches!(check_flash_repay(foreign.into_iter(), &pool_a), Err(SpiceError::FlashLoanNotRepaid)));
+
+        let other_instruction = vec![instruction(spice_program::ID, &Swap::DISCRIMINATOR, pool_a)];
+        assert!(matches!(check_flash_repay(other_instruction.into_iter(), &pool_a), Err(SpiceError::FlashLoanNotRepaid)));
+    }
+
+    #[test]
+    fn rejecting_repay_to_wrong_pool() {
+        let (pool_a, pool_b) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let instructions = vec![instruction(spice_program::ID, &FlashRepay::DISCRIMINATOR, pool_b)];
+
+        assert!(matches!(check_flash_repay(instructions.into_iter(), &pool_a), Err(SpiceError::FlashLoanNotRepaid)));
+    }
+
+    #[test]
+    fn charging_flash_loan_fee() {
+        assert_eq!(flash_loan_fee(0, 1_000_000).unwrap(), 0);
+        assert_eq!(flash_loan_fee(90, 1_000_000).unwrap(), 900);
+        assert_eq!(flash_loan_fee(90, 1_000).unwrap(), 0);
+    }
+
+    #[test]
+    fn borrowed_liquidity_is_not_an_imbalance() {
+        let pool_a = pool(1_000_000, 1_000_000);
+        let balanced = pool(1_000_000, 1_000_000);
+        let mut borrowed = pool(1_000_000, 400_000);
+        borrowed.flash_loan_amount = 600_000;
+
+        // The fee only reflects the lower available liquidity, not an imbalance
+        let balanced_fee = dynamic_fee(&pool_a, &balanced, 10_000).unwrap();
+        let borrowed_fee = dynamic_fee(&pool_a, &borrowed, 10_000).unwrap();
+        assert!(borrowed_fee >= balanced_fee);
+        assert!(borrowed_fee <= borrowed.regular_fee.max_fee);
+    }
+}
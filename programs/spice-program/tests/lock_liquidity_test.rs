@@ -0,0 +1,127 @@
+use spice_program::{
+    constants::SPICE_SCALE,
+    functions::{expire_position_boost, lock_provider, settle_yield, sync_lp_balance, unlock_provider},
+    states::{Pool, Provider},
+};
+
+const DAY: i64 = 86_400;
+
+#[cfg(test)]
+mod lock_liquidity {
+
+    use super::*;
+
+    #[test]
+    fn locked_lp_earns_boosted_yield() {
+        let mut pool = Pool { lp_supply: 2_000, ..Default::default() };
+        let mut locker = Provider { lp_balance: 1_000, ..Default::default() };
+        let mut holder = Provider { lp_balance: 1_000, ..Default::default() };
+
+        lock_provider(&mut pool, &mut locker, 1_000, 180 * DAY, 0).unwrap();
+        assert_eq!(locker.lp_boost, 500);
+        assert_eq!(pool.total_lp_boost, 500);
+
+        pool.cumulative_yield = 2_500 * SPICE_SCALE;
+        settle_yield(&pool, &mut locker, 0).unwrap();
+        settle_yield(&pool, &mut holder, 0).unwrap();
+
+        assert_eq!(locker.pending_claim, 1_500);
+        assert_eq!(holder.pending_claim, 1_000);
+    }
+
+    #[test]
+    fn expired_boost_stops_earning() {
+        let mut pool = Pool { lp_supply: 2_000, ..Default::default() };
+        let mut locker = Provider { lp_balance: 1_000, ..Default::default() };
+        let mut holder = Provider { lp_balance: 1_000, ..Default::default() };
+
+        lock_provider(&mut pool, &mut locker, 1_000, 30 * DAY, 0).unwrap();
+        assert_eq!(locker.lp_boost, 100);
+
+        // Settling after expiry without unlocking pays the plain share
+        pool.cumulative_yield = 2_100 * SPICE_SCALE;
+        settle_yield(&pool, &mut locker, 30 * DAY).unwrap();
+        settle_yield(&pool, &mut holder, 30 * DAY).unwrap();
+        assert_eq!(locker.pending_claim, 1_000);
+        assert_eq!(holder.pending_claim, 1_000);
+
+        // Syncing drops the boost from the pool total, so the others are no longer diluted
+        sync_lp_balance(&mut pool, &mut locker, 0, 30 * DAY).unwrap();
+        assert_eq!(locker.lp_boost, 0);
+        assert_eq!(pool.total_lp_boost, 0);
+        assert_eq!(locker.locked_lp, 1_000);
+
+        pool.cumulative_yield += 2_000 * SPICE_SCALE;
+        settle_yield(&pool, &mut locker, 31 * DAY).unwrap();
+        settle_yield(&pool, &mut holder, 31 * DAY).unwrap();
+        assert_eq!(locker.pending_claim, 2_000);
+        assert_eq!(holder.pending_claim, 2_000);
+    }
+
+    #[test]
+    fn cranking_expired_boost() {
+        let mut pool = Pool { lp_supply: 2_000, ..Default::default() };
+        let mut locker = Provider { lp_balance: 1_000, ..Default::default() };
+
+        lock_provider(&mut pool, &mut locker, 1_000, 30 * DAY, 0).unwrap();
+        assert!(expire_position_boost(&mut pool, &mut locker, 30 * DAY - 1).is_err());
+
+        // Anyone can drop the boost once the lock ran out, the position keeps its LP
+        pool.cumulative_yield = 2_100 * SPICE_SCALE;
+        assert_eq!(expire_position_boost(&mut pool, &mut locker, 30 * DAY).unwrap(), 100);
+        assert_eq!(pool.total_lp_boost, 0);
+        assert_eq!(locker.lp_boost, 0);
+        assert_eq!(locker.lp_balance, 1_000);
+        assert_eq!(locker.locked_lp, 1_000);
+        assert_eq!(locker.pending_claim, 1_000);
+
+        assert_eq!(expire_position_boost(&mut pool, &mut locker, 31 * DAY).unwrap(), 0);
+    }
+
+    #[test]
+    fn sync_keeps_escrowed_lp() {
+        let mut pool = Pool { lp_supply: 1_000, ..Default::default() };
+        let mut provider = Provider { lp_balance: 1_000, locked_lp: 400, ..Default::default() };
+
+        sync_lp_balance(&mut pool, &mut provider, 600, 0).unwrap();
+        assert_eq!(provider.lp_balance, 1_000);
+    }
+
+    #[test]
+    fn lock_terms_and_amounts() {
+        let mut pool = Pool { lp_supply: 1_000, ..Default::default() };
+        let mut provider = Provider { lp_balance: 1_000, ..Default::default() };
+
+        assert!(lock_provider(&mut pool, &mut provider, 100, 45 * DAY, 0).is_err());
+        assert!(lock_provider(&mut pool, &mut provider, 0, 30 * DAY, 0).is_err());
+        assert!(lock_provider(&mut pool, &mut provider, 1_001, 30 * DAY, 0).is_err());
+
+        lock_provider(&mut pool, &mut provider, 600, 90 * DAY, 0).unwrap();
+
+        // Only the unlocked part can be added, and not with a shorter lock
+        assert!(lock_provider(&mut pool, &mut provider, 500, 180 * DAY, 0).is_err());
+        assert!(lock_provider(&mut pool, &mut provider, 100, 30 * DAY, DAY).is_err());
+
+        lock_provider(&mut pool, &mut provider, 400, 90 * DAY, DAY).unwrap();
+        assert_eq!(provider.locked_lp, 1_000);
+        assert_eq!(provider.lock_end, 91 * DAY);
+        assert_eq!(provider.lp_boost, 250);
+        assert_eq!(pool.total_lp_boost, 250);
+    }
+
+    #[test]
+    fn unlock_after_expiry() {
+        let mut pool = Pool { lp_supply: 1_000, ..Default::default() };
+        let mut provider = Provider { lp_balance: 1_000, ..Default::default() };
+
+        lock_provider(&mut pool, &mut provider, 500, 30 * DAY, 0).unwrap();
+        assert!(unlock_provider(&mut pool, &mut provider, 30 * DAY - 1).is_err());
+
+        assert_eq!(unlock_provider(&mut pool, &mut provider, 30 * DAY).unwrap(), 500);
+        assert_eq!(provider.locked_lp, 0);
+        assert_eq!(provider.lp_boost, 0);
+        assert_eq!(pool.total_lp_boost, 0);
+
+        assert!(unlock_provider(&mut pool, &mut provider, 30 * DAY).is_err());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,19 @@
+use spice_program::functions::{lp_token_name, lp_token_symbol};
+
+#[cfg(test)]
+mod lp_metadata {
+
+    use super::*;
+
+    #[test]
+    fn derived_from_underlying_metadata() {
+        assert_eq!(lp_token_name("USD Coin\0\0\0\0"), "Spice USD Coin LP");
+        assert_eq!(lp_token_symbol("USDC\0\0\0\0\0\0"), "spUSDC");
+    }
+
+    #[test]
+    fn truncated_to_metaplex_limits() {
+        assert_eq!(lp_token_name("A very long token name for a pool"), "Spice A very long token name for");
+        assert_eq!(lp_token_symbol("LONGSYMBOL"), "spLONGSYMB");
+    }
+}
\ No newline at end of file
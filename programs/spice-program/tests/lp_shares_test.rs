@@ -0,0 +1,133 @@
+use spice_program::{
+    constants::MINIMUM_LP_SUPPLY,
+    functions::{deposit_liquidity, pool_value, withdraw_liquidity},
+    states::Pool,
+};
+
+#[cfg(test)]
+mod lp_shares {
+
+    use super::*;
+
+    #[test]
+    fn first_deposit_mints_one_to_one() {
+        let mut pool = Pool::default();
+
+        // The minimum supply is locked out of the first deposit
+        assert!(deposit_liquidity(&mut pool, MINIMUM_LP_SUPPLY).is_err());
+        assert_eq!(deposit_liquidity(&mut pool, 1_000_000).unwrap(), 1_000_000 - MINIMUM_LP_SUPPLY);
+        assert_eq!(pool.lp_supply, 1_000_000);
+        assert_eq!(pool.initial_liquidity, 1_000_000);
+        assert_eq!(pool.current_liquidity, 1_000_000);
+    }
+
+    #[test]
+    fn donation_does_not_inflate_small_supply() {
+        let mut pool = Pool::default();
+        let attacker = deposit_liquidity(&mut pool, MINIMUM_LP_SUPPLY + 1).unwrap();
+        assert_eq!(attacker, 1);
+
+        // The attacker donates to the pool to push up the LP price
+        pool.current_liquidity += 1_000_000;
+
+        let victim = deposit_liquidity(&mut pool, 1_000_000).unwrap();
+        assert!(victim > 0);
+        assert!(withdraw_liquidity(&mut pool, victim).unwrap() >= 999_000);
+
+        // Most of the donation stays with the locked supply
+        assert!(withdraw_liquidity(&mut pool, attacker).unwrap() < 2_000);
+    }
+
+    #[test]
+    fn leftover_value_stays_with_locked_supply() {
+        let mut pool = Pool { current_liquidity: 5_000, ..Default::default() };
+
+        let lp = deposit_liquidity(&mut pool, 1_000).unwrap();
+        assert_eq!(lp, 1_000);
+        assert_eq!(pool.lp_supply, 6_000);
+        assert_eq!(withdraw_liquidity(&mut pool, lp).unwrap(), 1_000);
+        assert_eq!(pool.current_liquidity, 5_000);
+    }
+
+    #[test]
+    fn early_exit_shares_losses() {
+        let mut pool = Pool::default();
+        let first = deposit_liquidity(&mut pool, 500_000).unwrap();
+        let second = deposit_liquidity(&mut pool, 500_000).unwrap();
+
+        // Pool loses 20% to traders
+        pool.current_liquidity -= 200_000;
+
+        let first_exit = withdraw_liquidity(&mut pool, first).unwrap();
+        assert_eq!(first_exit, 399_200);
+
+        let second_exit = withdraw_liquidity(&mut pool, second).unwrap();
+        assert_eq!(second_exit, 400_000);
+
+        // Only the locked supply and its share are left
+        assert_eq!(pool.lp_supply, MINIMUM_LP_SUPPLY);
+        assert_eq!(pool.current_liquidity, 800);
+        assert_eq!(pool.initial_liquidity, 1_000);
+    }
+
+    #[test]
+    fn early_exit_keeps_pool_delta_ratio() {
+        let mut pool = Pool::default();
+        let first = deposit_liquidity(&mut pool, 600_000).unwrap();
+        deposit_liquidity(&mut pool, 400_000).unwrap();
+
+        pool.current_liquidity -= 100_000;
+
+        withdraw_liquidity(&mut pool, first).unwrap();
+        assert_eq!(pool.initial_liquidity, 401_000);
+        assert_eq!(pool.current_liquidity, 360_900);
+    }
+
+    #[test]
+    fn gains_are_shared_pro_rata() {
+        let mut pool = Pool::default();
+        let first = deposit_liquidity(&mut pool, 300_000).unwrap();
+        let second = deposit_liquidity(&mut pool, 100_000).unwrap();
+
+        pool.current_liquidity += 40_000;
+
+        assert_eq!(withdraw_liquidity(&mut pool, second).unwrap(), 110_000);
+        assert_eq!(withdraw_liquidity(&mut pool, first).unwrap(), 328_900);
+    }
+
+    #[test]
+    fn late_deposit_priced_at_pool_value() {
+        let mut pool = Pool::default();
+        deposit_liquidity(&mut pool, 1_000_000).unwrap();
+        pool.current_liquidity -= 500_000;
+
+        // Half price shares, the newcomer does not absorb the existing loss
+        let late = deposit_liquidity(&mut pool, 500_000).unwrap();
+        assert_eq!(late, 1_000_000);
+        assert_eq!(withdraw_liquidity(&mut pool, late).unwrap(), 500_000);
+    }
+
+    #[test]
+    fn flash_loan_counts_towards_value() {
+        let mut pool = Pool::default();
+        deposit_liquidity(&mut pool, 1_000_000).unwrap();
+
+        pool.current_liquidity -= 400_000;
+        pool.flash_loan_amount = 400_000;
+
+        assert_eq!(pool_value(&pool).unwrap(), 1_000_000);
+        assert_eq!(deposit_liquidity(&mut pool, 100_000).unwrap(), 100_000);
+    }
+
+    #[test]
+    fn rounding_favors_the_pool() {
+        let mut pool = Pool::default();
+        deposit_liquidity(&mut pool, 1_000_000).unwrap();
+        pool.current_liquidity += 1;
+
+        let lp = deposit_liquidity(&mut pool, 999).unwrap();
+        assert_eq!(lp, 998);
+        assert!(withdraw_liquidity(&mut pool, lp).unwrap() <= 999);
+        assert!(withdraw_liquidity(&mut pool, 2_000_000).is_err());
+    }
+}
\ No newline at end of file
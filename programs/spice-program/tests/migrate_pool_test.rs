@@ -0,0 +1,84 @@
+use anchor_lang::prelude::{Pubkey, AnchorSerialize};
+use spice_program::{
+    constants::{FEE_SCALE, LEGACY_POOL_SPACE, MAX_FEE_BREAKPOINTS, POOL_SPACE, POOL_VERSION, SPICE_SCALE},
+    errors::SpiceError,
+    functions::migrate_legacy_pool,
+    states::{FeeBreakpoint, FeeCurve, LegacyPool, Pool},
+};
+
+fn legacy_pool() -> LegacyPool {
+    LegacyPool {
+        is_active: true,
+        base_fee: 10,
+        mint: Pubkey::new_unique(),
+        pyth_price_feed_account: Pubkey::new_unique(),
+        lp_token_mint: Pubkey::new_unique(),
+        initial_liquidity: 1_000_000,
+        cumulative_yield: 4_000,
+        current_liquidity: 990_000,
+        protocol_income: 0,
+    }
+}
+
+#[cfg(test)]
+mod migrate_pool {
+
+    use super::*;
+
+    #[test]
+    fn keeping_liquidity_counters() {
+        let legacy = legacy_pool();
+        let pool = migrate_legacy_pool(&legacy, 1_000_000, 0).unwrap();
+
+        assert!(pool.is_active);
+        assert_eq!(pool.base_fee, 10);
+        assert_eq!(pool.mint, legacy.mint);
+        assert_eq!(pool.lp_token_mint, legacy.lp_token_mint);
+        assert_eq!(pool.initial_liquidity, 1_000_000);
+        assert_eq!(pool.current_liquidity, 990_000);
+        assert_eq!(pool.cumulative_yield, 4_000);
+        assert_eq!(pool.lp_supply, 1_000_000);
+        assert_eq!(pool.version, POOL_VERSION);
+    }
+
+    #[test]
+    fn starting_with_pool_defaults() {
+        let pool = migrate_legacy_pool(&legacy_pool(), 1_000_000, 0).unwrap();
+
+        assert_eq!(pool.deficit_coverage, FEE_SCALE as u64);
+        assert_eq!(pool.withdrawal_fee.max_fee, 5_000);
+        assert_eq!(pool.imbalance_fee.max_fee, 10_000);
+        assert_eq!(pool.regular_fee.max_fee, 1_000);
+        assert!(pool.protocol_share.is_none());
+        assert!(pool.fee_shares.iter().all(|share| share.amount == 0));
+    }
+
+    #[test]
+    fn moving_protocol_income_out_of_liquidity() {
+        // 3_000 income accrued inside the liquidity, 1_000 of it was collected without updating it
+        let legacy = LegacyPool { protocol_income: 3_000 * SPICE_SCALE + 999, ..legacy_pool() };
+        let pool = migrate_legacy_pool(&legacy, 1_000_000, 1_000).unwrap();
+
+        assert_eq!(pool.protocol_income, 2_000);
+        assert_eq!(pool.current_liquidity, 987_000);
+        assert_eq!(pool.initial_liquidity, 1_000_000);
+
+        assert!(matches!(migrate_legacy_pool(&legacy, 1_000_000, 3_001), Err(SpiceError::InvalidProtocolIncomeAmount)));
+
+        let drained = LegacyPool { current_liquidity: 2_999, ..legacy };
+        assert!(matches!(migrate_legacy_pool(&drained, 1_000_000, 1_000), Err(SpiceError::InsufficientLiquidity)));
+    }
+
+    #[test]
+    fn account_spaces() {
+        assert_eq!(8 + legacy_pool().try_to_vec().unwrap().len(), LEGACY_POOL_SPACE);
+
+        // Largest variants of the enum and option fields
+        let pool = Pool {
+            fee_curve: FeeCurve::PiecewiseLinear { breakpoints: [FeeBreakpoint::default(); MAX_FEE_BREAKPOINTS], count: 0 },
+            protocol_share: Some(0),
+            ..migrate_legacy_pool(&legacy_pool(), 0, 0).unwrap()
+        };
+        assert_eq!(8 + pool.try_to_vec().unwrap().len(), POOL_SPACE);
+    }
+}
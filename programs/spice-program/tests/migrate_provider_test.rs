@@ -0,0 +1,60 @@
+use anchor_lang::prelude::Pubkey;
+use spice_program::{
+    constants::SPICE_SCALE,
+    functions::{add_reward_stream, migrate_legacy_provider, settle_rewards, update_reward_streams},
+    states::{LegacyProvider, Pool},
+};
+
+fn legacy_provider() -> LegacyProvider {
+    LegacyProvider {
+        address: Pubkey::new_unique(),
+        lp_balance: 1_000,
+        last_cumulative_yield: 0,
+        pending_claim: 5,
+    }
+}
+
+#[cfg(test)]
+mod migrate_provider {
+
+    use super::*;
+
+    #[test]
+    fn settling_legacy_yield() {
+        let pool = Pool { lp_supply: 2_000, cumulative_yield: 1_000 * SPICE_SCALE, ..Default::default() };
+        let legacy = legacy_provider();
+
+        let provider = migrate_legacy_provider(&pool, &legacy, 0, 1_000, 0).unwrap();
+
+        assert_eq!(provider.address, legacy.address);
+        assert_eq!(provider.index, 0);
+        assert_eq!(provider.lp_balance, 1_000);
+        assert_eq!(provider.pending_claim, 505);
+        assert_eq!(provider.last_cumulative_yield, 1_000 * SPICE_SCALE);
+    }
+
+    #[test]
+    fn holding_only_the_lp_moved_in() {
+        let pool = Pool { lp_supply: 2_000, ..Default::default() };
+
+        let provider = migrate_legacy_provider(&pool, &legacy_provider(), 3, 400, 0).unwrap();
+
+        assert_eq!(provider.index, 3);
+        assert_eq!(provider.lp_balance, 400);
+    }
+
+    #[test]
+    fn no_rewards_from_before_migration() {
+        let mut pool = Pool { lp_supply: 2_000, ..Default::default() };
+        add_reward_stream(&mut pool, 0, Pubkey::new_unique(), Pubkey::new_unique(), 1_000, (100, 200), 50).unwrap();
+        update_reward_streams(&mut pool, 150).unwrap();
+
+        let mut provider = migrate_legacy_provider(&pool, &legacy_provider(), 0, 1_000, 150).unwrap();
+        settle_rewards(&pool, &mut provider).unwrap();
+        assert_eq!(provider.reward_debts[0].pending, 0);
+
+        update_reward_streams(&mut pool, 200).unwrap();
+        settle_rewards(&pool, &mut provider).unwrap();
+        assert_eq!(provider.reward_debts[0].pending, 25_000);
+    }
+}
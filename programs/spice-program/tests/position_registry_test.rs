@@ -0,0 +1,33 @@
+use spice_program::{
+    functions::{position_indices, register_position, unregister_position},
+    states::PositionRegistry,
+};
+
+#[cfg(test)]
+mod position_registry {
+
+    use super::*;
+
+    #[test]
+    fn listing_position_indices() {
+        let mut registry = PositionRegistry::default();
+        assert!(position_indices(&registry).is_empty());
+
+        register_position(&mut registry, 0).unwrap();
+        register_position(&mut registry, 5).unwrap();
+        register_position(&mut registry, 63).unwrap();
+        register_position(&mut registry, 5).unwrap();
+        assert_eq!(position_indices(&registry), vec![0, 5, 63]);
+
+        unregister_position(&mut registry, 5).unwrap();
+        assert_eq!(position_indices(&registry), vec![0, 63]);
+    }
+
+    #[test]
+    fn index_out_of_range() {
+        let mut registry = PositionRegistry::default();
+
+        assert!(register_position(&mut registry, 64).is_err());
+        assert!(unregister_position(&mut registry, 64).is_err());
+    }
+}
\ No newline at end of file
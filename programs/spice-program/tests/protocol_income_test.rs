@@ -0,0 +1,119 @@
+mod common;
+
+use common::{pool, settings};
+use proptest::prelude::*;
+use spice_program::{
+    constants::SPICE_SCALE,
+    errors::SpiceError,
+    functions::{check_protocol_share, distribute_fee, protocol_share_from_divisor, take_protocol_income},
+    states::Pool,
+};
+
+fn pool_with_income(initial_liquidity: u64, current_liquidity: u64, protocol_income: u64) -> Pool {
+    Pool { protocol_income, ..pool(initial_liquidity, current_liquidity) }
+}
+
+#[cfg(test)]
+mod protocol_income {
+
+    use super::*;
+
+    #[test]
+    fn accruing_unscaled_income() {
+        let mut pool_a = pool_with_income(1_000_000, 1_000_000, 0);
+
+        distribute_fee(&mut pool_a, 1_001, &settings(50_000)).unwrap();
+        assert_eq!(pool_a.protocol_income, 500);
+
+        distribute_fee(&mut pool_a, 300, &settings(30_000)).unwrap();
+        assert_eq!(pool_a.protocol_income, 590);
+        assert_eq!(pool_a.cumulative_yield, (501 + 210) * SPICE_SCALE);
+    }
+
+    #[test]
+    fn overriding_protocol_share_per_pool() {
+        let mut pool_a = Pool { protocol_share: Some(0), ..pool(1_000_000, 1_000_000) };
+        distribute_fee(&mut pool_a, 1_000, &settings(50_000)).unwrap();
+        assert_eq!(pool_a.protocol_income, 0);
+        assert_eq!(pool_a.cumulative_yield, 1_000 * SPICE_SCALE);
+
+        pool_a.protocol_share = Some(100_000);
+        distribute_fee(&mut pool_a, 1_000, &settings(50_000)).unwrap();
+        assert_eq!(pool_a.protocol_income, 1_000);
+
+        pool_a.protocol_share = None;
+        distribute_fee(&mut pool_a, 1_000, &settings(30_000)).unwrap();
+        assert_eq!(pool_a.protocol_income, 1_300);
+    }
+
+    #[test]
+    fn validating_protocol_share() {
+        assert!(check_protocol_share(0).is_ok());
+        assert!(check_protocol_share(100_000).is_ok());
+        assert!(check_protocol_share(100_001).is_err());
+
+        let mut pool_a = pool_with_income(1_000_000, 1_000_000, 0);
+        assert!(matches!(distribute_fee(&mut pool_a, 1_000, &settings(100_001)), Err(SpiceError::InvalidProtocolShare)));
+    }
+
+    #[test]
+    fn migrating_income_divisor() {
+        assert_eq!(protocol_share_from_divisor(1), 100_000);
+        assert_eq!(protocol_share_from_divisor(2), 50_000);
+        assert_eq!(protocol_share_from_divisor(3), 33_333);
+        assert_eq!(protocol_share_from_divisor(0), 0);
+    }
+
+    #[test]
+    fn collecting_all_income() {
+        let mut pool_a = pool_with_income(1_000_000, 1_000_000, 700);
+
+        assert_eq!(take_protocol_income(&mut pool_a, None).unwrap(), 700);
+        assert_eq!(pool_a.protocol_income, 0);
+        assert!(matches!(take_protocol_income(&mut pool_a, None), Err(SpiceError::InvalidProtocolIncomeAmount)));
+    }
+
+    #[test]
+    fn collecting_part_of_income() {
+        let mut pool_a = pool_with_income(1_000_000, 1_000_000, 700);
+
+        assert_eq!(take_protocol_income(&mut pool_a, Some(300)).unwrap(), 300);
+        assert_eq!(pool_a.protocol_income, 400);
+        assert!(matches!(take_protocol_income(&mut pool_a, Some(401)), Err(SpiceError::InvalidProtocolIncomeAmount)));
+        assert_eq!(take_protocol_income(&mut pool_a, Some(400)).unwrap(), 400);
+    }
+
+    #[test]
+    fn no_collection_in_deficit() {
+        let mut pool_a = pool_with_income(1_000_000, 999_999, 700);
+
+        assert!(matches!(take_protocol_income(&mut pool_a, None), Err(SpiceError::PoolInDeficit)));
+        assert_eq!(pool_a.protocol_income, 700);
+    }
+
+    proptest! {
+        #[test]
+        fn never_collecting_more_than_accrued(
+            fees in prop::collection::vec(0u64..1_000_000, 1..20),
+            protocol_share in 0u64..=100_000,
+            amounts in prop::collection::vec(prop::option::of(0u64..2_000_000), 1..20),
+        ) {
+            let mut pool_a = pool_with_income(1_000_000, 1_000_000, 0);
+            let mut accrued = 0;
+            let mut collected = 0;
+
+            for (fee, amount) in fees.iter().zip(amounts.iter().cycle()) {
+                let before = pool_a.protocol_income;
+                distribute_fee(&mut pool_a, *fee, &settings(protocol_share)).unwrap();
+                accrued += pool_a.protocol_income - before;
+
+                if let Ok(amount) = take_protocol_income(&mut pool_a, *amount) {
+                    collected += amount;
+                }
+
+                prop_assert!(collected <= accrued);
+                prop_assert_eq!(accrued - collected, pool_a.protocol_income);
+            }
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use anchor_lang::prelude::Pubkey;
+use spice_program::{
+    constants::{MAX_REWARD_DURATION, MIN_REWARD_RATE, REWARD_CLAIM_WINDOW},
+    errors::SpiceError,
+    functions::{add_reward_stream, free_reward_slot, refund_replaced_stream, refund_reward_stream, sync_lp_balance, take_rewards, update_reward_streams},
+    states::{Pool, Provider},
+};
+
+const FUNDER: Pubkey = Pubkey::new_from_array([7; 32]);
+
+#[cfg(test)]
+mod reward_streams {
+
+    use super::*;
+
+    fn pool_with_stream(lp_supply: u64) -> Pool {
+        let mut pool = Pool { lp_supply, ..Default::default() };
+        let funding = add_reward_stream(&mut pool, 0, FUNDER, Pubkey::new_unique(), 1_000, (100, 200), 50).unwrap();
+        assert_eq!(funding, 100_000);
+        pool
+    }
+
+    #[test]
+    fn rewards_accrue_pro_rata() {
+        let mut pool = pool_with_stream(1_000);
+        let mut alice = Provider { lp_balance: 750, ..Default::default() };
+        let mut bob = Provider { lp_balance: 250, ..Default::default() };
+
+        // Nothing is emitted before the start
+        update_reward_streams(&mut pool, 90).unwrap();
+        sync_lp_balance(&mut pool, &mut alice, 750, 0).unwrap();
+        assert_eq!(alice.reward_debts[0].pending, 0);
+
+        update_reward_streams(&mut pool, 140).unwrap();
+        sync_lp_balance(&mut pool, &mut alice, 750, 0).unwrap();
+        sync_lp_balance(&mut pool, &mut bob, 250, 0).unwrap();
+        assert_eq!(alice.reward_debts[0].pending, 30_000);
+        assert_eq!(bob.reward_debts[0].pending, 10_000);
+
+        // Emission stops at the end of the stream
+        update_reward_streams(&mut pool, 1_000).unwrap();
+        sync_lp_balance(&mut pool, &mut alice, 750, 0).unwrap();
+        sync_lp_balance(&mut pool, &mut bob, 250, 0).unwrap();
+        assert_eq!(alice.reward_debts[0].pending, 75_000);
+        assert_eq!(bob.reward_debts[0].pending, 25_000);
+        assert_eq!(pool.reward_streams[0].distributed, 100_000);
+
+        assert_eq!(take_rewards(&pool, &mut alice, 0).unwrap(), 75_000);
+        assert_eq!(take_rewards(&pool, &mut alice, 0).unwrap(), 0);
+    }
+
+    #[test]
+    fn liquidity_change_settles_reward_debt() {
+        let mut pool = pool_with_stream(1_000);
+        let mut alice = Provider { lp_balance: 1_000, ..Default::default() };
+        let mut bob = Provider::default();
+
+        update_reward_streams(&mut pool, 150).unwrap();
+        sync_lp_balance(&mut pool, &mut alice, 1_000, 0).unwrap();
+
+        // Bob joins halfway and doesn't earn the first half
+        sync_lp_balance(&mut pool, &mut bob, 0, 0).unwrap();
+        bob.lp_balance = 1_000;
+        pool.lp_supply = 2_000;
+
+        update_reward_streams(&mut pool, 200).unwrap();
+        sync_lp_balance(&mut pool, &mut alice, 1_000, 0).unwrap();
+        sync_lp_balance(&mut pool, &mut bob, 1_000, 0).unwrap();
+        assert_eq!(alice.reward_debts[0].pending, 75_000);
+        assert_eq!(bob.reward_debts[0].pending, 25_000);
+    }
+
+    #[test]
+    fn stream_slots() {
+        let mut pool = pool_with_stream(0);
+        let mint = Pubkey::new_unique();
+
+        assert!(add_reward_stream(&mut pool, 3, FUNDER, mint, 1_000, (100, 200), 50).is_err());
+        assert!(add_reward_stream(&mut pool, 1, FUNDER, mint, 1_000, (40, 200), 50).is_err());
+        assert!(add_reward_stream(&mut pool, 1, FUNDER, mint, 1_000, (200, 200), 50).is_err());
+        assert!(add_reward_stream(&mut pool, 1, FUNDER, mint, 0, (100, 200), 50).is_err());
+
+        // A running stream can't be replaced
+        assert!(add_reward_stream(&mut pool, 0, FUNDER, mint, 1_000, (150, 200), 150).is_err());
+        assert_eq!(free_reward_slot(&pool, 150).unwrap(), 1);
+
+        // An ended one only after the claim window passed and the undistributed part is refunded
+        update_reward_streams(&mut pool, 200).unwrap();
+        let reuse = 200 + REWARD_CLAIM_WINDOW;
+        assert!(matches!(
+            add_reward_stream(&mut pool, 0, FUNDER, mint, 1_000, (reuse, reuse + 100), reuse),
+            Err(SpiceError::RewardStreamNotRefunded)
+        ));
+
+        refund_reward_stream(&mut pool, 0, 200).unwrap();
+        assert!(add_reward_stream(&mut pool, 0, FUNDER, mint, 1_000, (reuse - 1, reuse + 100), reuse - 1).is_err());
+        add_reward_stream(&mut pool, 0, FUNDER, mint, 1_000, (reuse, reuse + 100), reuse).unwrap();
+        assert_eq!(pool.reward_streams[0].id, 2);
+        assert_eq!(pool.reward_streams[0].funder, FUNDER);
+    }
+
+    #[test]
+    fn undistributed_incentive_refund() {
+        let mut pool = pool_with_stream(0);
+
+        // No LP for the first 40 seconds
+        update_reward_streams(&mut pool, 140).unwrap();
+        pool.lp_supply = 1_000;
+
+        assert!(refund_reward_stream(&mut pool, 0, 150).is_err());
+
+        // Not updated up to the end yet
+        assert!(refund_reward_stream(&mut pool, 0, 250).is_err());
+
+        update_reward_streams(&mut pool, 250).unwrap();
+        assert_eq!(pool.reward_streams[0].distributed, 60_000);
+        assert_eq!(refund_reward_stream(&mut pool, 0, 250).unwrap(), 40_000);
+        assert_eq!(refund_reward_stream(&mut pool, 0, 250).unwrap(), 0);
+    }
+
+    #[test]
+    fn no_free_slot() {
+        let mut pool = pool_with_stream(1_000);
+        add_reward_stream(&mut pool, 1, FUNDER, Pubkey::new_unique(), MIN_REWARD_RATE, (100, 200), 50).unwrap();
+
+        // The last slot is reserved for the admin
+        assert!(free_reward_slot(&pool, 50).is_err());
+        add_reward_stream(&mut pool, 2, FUNDER, Pubkey::new_unique(), MIN_REWARD_RATE, (100, 200), 50).unwrap();
+    }
+
+    #[test]
+    fn rejecting_squatting_and_liquidity_mints() {
+        let (pool_mint, lp_token_mint, mint) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let mut pool = Pool { mint: pool_mint, lp_token_mint, ..Default::default() };
+
+        // Dust streams can't hold a slot
+        assert!(add_reward_stream(&mut pool, 0, FUNDER, mint, 1, (100, 200), 50).is_err());
+        assert!(add_reward_stream(&mut pool, 0, FUNDER, mint, MIN_REWARD_RATE - 1, (100, 200), 50).is_err());
+
+        assert!(add_reward_stream(&mut pool, 0, FUNDER, mint, MIN_REWARD_RATE, (100, 50 + MAX_REWARD_DURATION + 1), 50).is_err());
+        assert!(add_reward_stream(&mut pool, 0, FUNDER, mint, MIN_REWARD_RATE, (MAX_REWARD_DURATION, MAX_REWARD_DURATION + 100), 50).is_err());
+        add_reward_stream(&mut pool, 0, FUNDER, mint, MIN_REWARD_RATE, (100, 50 + MAX_REWARD_DURATION), 50).unwrap();
+
+        assert!(add_reward_stream(&mut pool, 1, FUNDER, pool_mint, MIN_REWARD_RATE, (100, 200), 50).is_err());
+        assert!(add_reward_stream(&mut pool, 1, FUNDER, lp_token_mint, MIN_REWARD_RATE, (100, 200), 50).is_err());
+    }
+
+    #[test]
+    fn reusing_slot_of_unrefunded_stream() {
+        // The pool never had LP, nothing was distributed and the funder never refunded
+        let mut pool = pool_with_stream(0);
+        let mint = Pubkey::new_unique();
+        update_reward_streams(&mut pool, 200).unwrap();
+
+        let reuse = 200 + REWARD_CLAIM_WINDOW;
+        assert!(free_reward_slot(&pool, reuse - 1).is_ok_and(|slot| slot != 0));
+        assert_eq!(free_reward_slot(&pool, reuse).unwrap(), 0);
+
+        // Anyone funding the slot refunds the replaced stream on the way
+        assert_eq!(refund_replaced_stream(&mut pool, 0, reuse).unwrap(), 100_000);
+        assert_eq!(refund_replaced_stream(&mut pool, 0, reuse).unwrap(), 0);
+        add_reward_stream(&mut pool, 0, Pubkey::new_unique(), mint, MIN_REWARD_RATE, (reuse, reuse + 100), reuse).unwrap();
+        assert_eq!(pool.reward_streams[0].mint, mint);
+
+        // An empty slot has nothing to refund
+        assert_eq!(refund_replaced_stream(&mut pool, 1, reuse).unwrap(), 0);
+    }
+}
\ No newline at end of file
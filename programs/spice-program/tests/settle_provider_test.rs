@@ -0,0 +1,216 @@
+use anchor_lang::prelude::Pubkey;
+use spice_program::{
+    constants::SPICE_SCALE,
+    errors::SpiceError,
+    functions::{check_provider_closable, compound_provider, deposit_position, merge_position, settle_yield, sync_lp_balance, sync_lp_transfer, take_keeper_tip},
+    states::{Pool, Provider},
+};
+
+#[cfg(test)]
+mod settle_provider {
+
+    use super::*;
+
+    #[test]
+    fn settling_yield_into_pending_claim() {
+        let mut pool = Pool { lp_supply: 1_000, cumulative_yield: 500 * SPICE_SCALE, ..Default::default() };
+        let mut provider = Provider { lp_balance: 200, ..Default::default() };
+
+        settle_yield(&pool, &mut provider, 0).unwrap();
+        assert_eq!(provider.pending_claim, 100);
+        assert_eq!(provider.last_cumulative_yield, 500 * SPICE_SCALE);
+
+        settle_yield(&pool, &mut provider, 0).unwrap();
+        assert_eq!(provider.pending_claim, 100);
+    }
+
+    #[test]
+    fn transferred_lp_stops_earning_after_sync() {
+        let mut pool = Pool { lp_supply: 1_000, ..Default::default() };
+        let mut sender = Provider { lp_balance: 600, ..Default::default() };
+        let mut recipient = Provider::default();
+
+        pool.cumulative_yield = 100 * SPICE_SCALE;
+
+        // 300 LP move to the recipient, both positions are synced in the same instruction
+        sync_lp_balance(&mut pool, &mut sender, 300, 0).unwrap();
+        sync_lp_balance(&mut pool, &mut recipient, 300, 0).unwrap();
+        assert_eq!(sender.pending_claim, 60);
+        assert_eq!(recipient.pending_claim, 0);
+
+        pool.cumulative_yield += 100 * SPICE_SCALE;
+
+        settle_yield(&pool, &mut sender, 0).unwrap();
+        settle_yield(&pool, &mut recipient, 0).unwrap();
+        assert_eq!(sender.pending_claim, 90);
+        assert_eq!(recipient.pending_claim, 30);
+    }
+
+    #[test]
+    fn plain_lp_transfer_keeps_each_side_yield() {
+        let mut pool = Pool { lp_supply: 1_000, ..Default::default() };
+        let mut sender = Provider { lp_balance: 600, ..Default::default() };
+        let mut receiver = Provider { lp_balance: 400, ..Default::default() };
+
+        pool.cumulative_yield = 100 * SPICE_SCALE;
+
+        // 300 LP move by a plain SPL transfer, the receiver syncing alone isn't credited yet
+        sync_lp_balance(&mut pool, &mut receiver, 700, 0).unwrap();
+        assert_eq!(receiver.lp_balance, 400);
+        assert_eq!(receiver.pending_claim, 40);
+
+        pool.cumulative_yield += 100 * SPICE_SCALE;
+
+        // Yield accrued before the sync stays with the balances each side was credited with
+        sync_lp_transfer(&mut pool, &mut sender, 300, &mut receiver, 700, 0).unwrap();
+        assert_eq!(sender.pending_claim, 120);
+        assert_eq!(receiver.pending_claim, 80);
+        assert_eq!(sender.lp_balance, 300);
+        assert_eq!(receiver.lp_balance, 700);
+        assert_eq!(pool.unsynced_lp, 0);
+
+        pool.cumulative_yield += 100 * SPICE_SCALE;
+
+        settle_yield(&pool, &mut sender, 0).unwrap();
+        settle_yield(&pool, &mut receiver, 0).unwrap();
+        assert_eq!(sender.pending_claim, 150);
+        assert_eq!(receiver.pending_claim, 150);
+    }
+
+    #[test]
+    fn lp_sent_outside_positions_is_held_until_received() {
+        let mut pool = Pool { lp_supply: 1_000, ..Default::default() };
+        let mut sender = Provider { lp_balance: 600, ..Default::default() };
+        let mut receiver = Provider { lp_balance: 400, ..Default::default() };
+
+        // 200 LP go to another venue, then come back to the receiver
+        sync_lp_balance(&mut pool, &mut sender, 400, 0).unwrap();
+        assert_eq!(pool.unsynced_lp, 200);
+
+        // LP the receiver holds beyond what was released is never credited
+        sync_lp_balance(&mut pool, &mut receiver, 900, 0).unwrap();
+        assert_eq!(receiver.lp_balance, 600);
+        assert_eq!(pool.unsynced_lp, 0);
+    }
+
+    #[test]
+    fn deposit_credits_beneficiary_not_payer() {
+        let mut pool = Pool {
+            lp_supply: 1_000,
+            initial_liquidity: 1_000,
+            current_liquidity: 1_000,
+            ..Default::default()
+        };
+        let (payer, beneficiary) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut payer_position = Provider { address: payer, ..Default::default() };
+        let mut beneficiary_position = Provider::default();
+
+        // The payer funds a deposit into the beneficiary's position
+        let lp_amount = deposit_position(&mut pool, &mut beneficiary_position, beneficiary, 2, 500, 0, 0).unwrap();
+        assert_eq!(lp_amount, 500);
+        assert_eq!(beneficiary_position.address, beneficiary);
+        assert_eq!(beneficiary_position.index, 2);
+        assert_eq!(beneficiary_position.lp_balance, 500);
+        assert_eq!(pool.lp_supply, 1_500);
+
+        // Yield on the deposit accrues to the beneficiary only
+        pool.cumulative_yield = 150 * SPICE_SCALE;
+        settle_yield(&pool, &mut beneficiary_position, 0).unwrap();
+        settle_yield(&pool, &mut payer_position, 0).unwrap();
+        assert_eq!(beneficiary_position.pending_claim, 50);
+        assert_eq!(payer_position.pending_claim, 0);
+        assert_eq!(payer_position.lp_balance, 0);
+        assert!(check_provider_closable(&payer_position).is_ok());
+    }
+
+    #[test]
+    fn compounding_yield_into_lp() {
+        let mut pool = Pool {
+            lp_supply: 1_000,
+            initial_liquidity: 1_000,
+            current_liquidity: 1_000,
+            cumulative_yield: 100 * SPICE_SCALE,
+            ..Default::default()
+        };
+        let mut provider = Provider { lp_balance: 500, pending_claim: 10, ..Default::default() };
+
+        let lp_amount = compound_provider(&mut pool, &mut provider, 0).unwrap();
+
+        assert_eq!(lp_amount, 60);
+        assert_eq!(provider.lp_balance, 560);
+        assert_eq!(provider.pending_claim, 0);
+        assert_eq!(pool.lp_supply, 1_060);
+        assert_eq!(pool.initial_liquidity, 1_060);
+        assert_eq!(pool.current_liquidity, 1_060);
+
+        // Nothing left to compound
+        assert!(compound_provider(&mut pool, &mut provider, 0).is_err());
+    }
+
+    #[test]
+    fn keeper_tip_from_pending_yield() {
+        let mut provider = Provider { pending_claim: 1_000, keeper_tip: 250, ..Default::default() };
+        assert!(take_keeper_tip(&mut provider).is_err());
+
+        provider.keeper_enabled = true;
+        assert_eq!(take_keeper_tip(&mut provider).unwrap(), 25);
+        assert_eq!(provider.pending_claim, 975);
+    }
+
+    #[test]
+    fn closing_requires_an_empty_position() {
+        let mut provider = Provider::default();
+        assert!(check_provider_closable(&provider).is_ok());
+
+        provider.reward_debts[1].pending = 1;
+        assert!(check_provider_closable(&provider).is_err());
+
+        provider.reward_debts[1].pending = 0;
+        provider.pending_claim = 1;
+        assert!(check_provider_closable(&provider).is_err());
+
+        provider.pending_claim = 0;
+        provider.locked_lp = 1;
+        assert!(check_provider_closable(&provider).is_err());
+
+        provider.locked_lp = 0;
+        provider.lp_balance = 1;
+        assert!(check_provider_closable(&provider).is_err());
+    }
+
+    #[test]
+    fn transferred_position_merges_into_destination() {
+        let mut pool = Pool { lp_supply: 1_000, cumulative_yield: 100 * SPICE_SCALE, ..Default::default() };
+        let mut source = Provider { lp_balance: 500, locked_lp: 200, lp_boost: 100, lock_end: 50, ..Default::default() };
+        let mut destination = Provider { lp_balance: 100, pending_claim: 5, lock_end: 10, ..Default::default() };
+        source.reward_debts[0].pending = 7;
+
+        sync_lp_balance(&mut pool, &mut source, 300, 0).unwrap();
+        sync_lp_balance(&mut pool, &mut destination, 100, 0).unwrap();
+        merge_position(&mut source, &mut destination).unwrap();
+
+        assert_eq!(destination.lp_balance, 600);
+        assert_eq!(destination.pending_claim, 75);
+        assert_eq!(destination.locked_lp, 200);
+        assert_eq!(destination.lp_boost, 100);
+        assert_eq!(destination.lock_end, 50);
+        assert_eq!(destination.reward_debts[0].pending, 7);
+        assert!(check_provider_closable(&source).is_ok());
+    }
+
+    #[test]
+    fn merge_cannot_extend_destination_lock() {
+        let mut dust = Provider { lp_balance: 1, locked_lp: 1, lp_boost: 1, lock_end: 180 * 86_400, ..Default::default() };
+        let mut victim = Provider { lp_balance: 1_000, locked_lp: 1_000, lp_boost: 100, lock_end: 86_400, ..Default::default() };
+
+        assert!(matches!(merge_position(&mut dust, &mut victim), Err(SpiceError::LockExtended)));
+        assert_eq!(victim.lock_end, 86_400);
+        assert_eq!(victim.locked_lp, 1_000);
+
+        // A lock ending no later than the destination's merges
+        dust.lock_end = 86_400;
+        merge_position(&mut dust, &mut victim).unwrap();
+        assert_eq!(victim.locked_lp, 1_001);
+        assert_eq!(victim.lock_end, 86_400);
+    }
+}
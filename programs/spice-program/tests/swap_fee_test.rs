@@ -0,0 +1,141 @@
+mod common;
+
+use common::{pool, settings};
+use spice_program::{
+    constants::SPICE_SCALE,
+    functions::{dynamic_fee, input_fee, quote_swap, rebalancing_rebate, settle_swap},
+    states::Pool,
+};
+
+#[cfg(test)]
+mod swap_fee {
+
+    use super::*;
+
+    #[test]
+    fn no_input_fee_when_filling_deficit() {
+        let pool_a = pool(1_000_000, 900_000);
+
+        assert_eq!(input_fee(&pool_a, 50_000).unwrap(), 0);
+        assert_eq!(input_fee(&pool_a, 100_000).unwrap(), 0);
+    }
+
+    #[test]
+    fn input_fee_on_surplus_only() {
+        let overshoot = input_fee(&pool(1_000_000, 900_000), 200_000).unwrap();
+        let surplus = input_fee(&pool(1_000_000, 1_000_000), 200_000).unwrap();
+
+        assert!(overshoot > 0);
+        assert!(overshoot < surplus);
+    }
+
+    #[test]
+    fn input_fee_grows_with_surplus() {
+        let balanced = input_fee(&pool(1_000_000, 1_000_000), 100_000).unwrap();
+        let over_target = input_fee(&pool(1_000_000, 1_500_000), 100_000).unwrap();
+
+        assert!(balanced > 0);
+        assert!(over_target > balanced);
+    }
+
+    #[test]
+    fn regular_fee_when_pool_a_is_further_below_target() {
+        let amount_out = 10_000;
+        let pool_b = pool(1_000_000, 900_000);
+
+        let imbalancing = dynamic_fee(&pool(1_000_000, 1_000_000), &pool_b, amount_out).unwrap();
+        let regular = dynamic_fee(&pool(1_000_000, 800_000), &pool_b, amount_out).unwrap();
+
+        assert!(regular < imbalancing);
+    }
+
+    #[test]
+    fn crediting_input_fee_to_pool_a() {
+        let mut pool_a = pool(1_000_000, 1_200_000);
+        let mut pool_b = pool(1_000_000, 1_000_000);
+
+        let quote = quote_swap(&pool_a, &pool_b, 100_000, (100, 100), (6, 6), 0, 0).unwrap();
+        assert!(quote.input_fee > 0);
+        assert_eq!(quote.raw_amount_out, 100_000 - quote.input_fee);
+
+        settle_swap(&mut pool_a, &mut pool_b, 100_000, &quote, &settings(50_000), 0).unwrap();
+
+        let input_protocol_income = quote.input_fee / 2;
+        assert_eq!(pool_a.cumulative_yield, (quote.input_fee - input_protocol_income) * SPICE_SCALE);
+        assert_eq!(pool_a.protocol_income, input_protocol_income);
+        assert_eq!(pool_a.current_liquidity, 1_200_000 + 100_000 - quote.input_fee);
+    }
+
+    fn rebate_pool(initial_liquidity: u64, current_liquidity: u64) -> Pool {
+        Pool {
+            rebate_rate: 1_000,
+            max_rebate_per_trade: 500,
+            max_rebate_per_epoch: 800,
+            protocol_income: 10_000,
+            ..pool(initial_liquidity, current_liquidity)
+        }
+    }
+
+    #[test]
+    fn rebate_for_filling_pool_a_deficit() {
+        let pool_a = pool(1_000_000, 990_000);
+        let pool_b = rebate_pool(1_000_000, 1_000_000);
+
+        // Half of the input fills the deficit
+        assert_eq!(rebalancing_rebate(&pool_a, &pool_b, 20_000, 20_000, 1).unwrap(), 100);
+        assert_eq!(rebalancing_rebate(&pool_a, &pool_b, 10_000, 10_000, 1).unwrap(), 100);
+        assert_eq!(rebalancing_rebate(&pool_a, &pool_b, 5_000, 5_000, 1).unwrap(), 50);
+    }
+
+    #[test]
+    fn rebate_for_draining_pool_b_surplus() {
+        let pool_a = pool(1_000_000, 1_000_000);
+        let pool_b = rebate_pool(1_000_000, 1_030_000);
+
+        assert_eq!(rebalancing_rebate(&pool_a, &pool_b, 20_000, 20_000, 1).unwrap(), 200);
+        assert_eq!(rebalancing_rebate(&pool_a, &pool_b, 20_000, 40_000, 1).unwrap(), 300);
+    }
+
+    #[test]
+    fn no_rebate_when_imbalancing() {
+        let pool_a = pool(1_000_000, 1_100_000);
+        let pool_b = rebate_pool(1_000_000, 900_000);
+
+        assert_eq!(rebalancing_rebate(&pool_a, &pool_b, 20_000, 20_000, 1).unwrap(), 0);
+    }
+
+    #[test]
+    fn capping_rebates() {
+        let pool_a = pool(1_000_000, 500_000);
+        let mut pool_b = rebate_pool(1_000_000, 1_000_000);
+
+        // Per trade
+        assert_eq!(rebalancing_rebate(&pool_a, &pool_b, 100_000, 100_000, 1).unwrap(), 500);
+
+        // Per epoch
+        pool_b.rebate_epoch = 1;
+        pool_b.epoch_rebates = 600;
+        assert_eq!(rebalancing_rebate(&pool_a, &pool_b, 100_000, 100_000, 1).unwrap(), 200);
+        assert_eq!(rebalancing_rebate(&pool_a, &pool_b, 100_000, 100_000, 2).unwrap(), 500);
+
+        // By protocol income
+        pool_b.protocol_income = 150;
+        assert_eq!(rebalancing_rebate(&pool_a, &pool_b, 100_000, 100_000, 2).unwrap(), 150);
+    }
+
+    #[test]
+    fn paying_rebate_from_protocol_income() {
+        let mut pool_a = pool(1_000_000, 900_000);
+        let mut pool_b = rebate_pool(1_000_000, 1_000_000);
+
+        let quote = quote_swap(&pool_a, &pool_b, 10_000, (100, 100), (6, 6), 0, 7).unwrap();
+        assert_eq!(quote.rebate, 100);
+
+        settle_swap(&mut pool_a, &mut pool_b, 10_000, &quote, &settings(50_000), 7).unwrap();
+
+        assert_eq!(pool_b.protocol_income, 10_000 + quote.protocol_fee / 2 - 100);
+        assert_eq!(pool_b.rebate_epoch, 7);
+        assert_eq!(pool_b.epoch_rebates, 100);
+        assert_eq!(pool_b.current_liquidity, 1_000_000 - quote.raw_amount_out);
+    }
+}
\ No newline at end of file
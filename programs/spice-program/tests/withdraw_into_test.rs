@@ -0,0 +1,79 @@
+mod common;
+
+use common::{pool, settings, withdrawal_fee_params};
+use spice_program::{
+    constants::SPICE_SCALE,
+    functions::withdraw_into,
+    states::Pool,
+};
+
+#[cfg(test)]
+mod cross_pool_withdrawal {
+
+    use super::*;
+
+    #[test]
+    fn deficit_pool_keeps_its_tokens() {
+        let mut pool_a = pool(1_000_000, 800_000);
+        let mut pool_b = pool(1_000_000, 1_200_000);
+
+        let (withdrawal, quote) = withdraw_into(&mut pool_a, &mut pool_b, 100_000, (100, 100), (6, 6), &settings(50_000), 0).unwrap();
+
+        assert_eq!(withdrawal.amount, 80_000);
+        assert_eq!(quote.input_fee, 0);
+        assert!(quote.amount_out < 80_000);
+
+        // Pool A only loses its target, pool B pays out
+        assert_eq!(pool_a.lp_supply, 900_000);
+        assert_eq!(pool_a.initial_liquidity, 900_000);
+        assert_eq!(pool_a.current_liquidity, 800_000);
+        assert!(pool_b.current_liquidity < 1_200_000);
+        assert_eq!(pool_b.initial_liquidity, 1_000_000);
+    }
+
+    #[test]
+    fn no_rebate_for_leaving_deficit_pool() {
+        let mut pool_a = pool(1_000_000, 800_000);
+        let mut pool_b = Pool {
+            rebate_rate: 1_000,
+            max_rebate_per_trade: 500,
+            max_rebate_per_epoch: 800,
+            protocol_income: 10_000,
+            ..pool(1_000_000, 1_000_000)
+        };
+
+        let (withdrawal, quote) = withdraw_into(&mut pool_a, &mut pool_b, 100_000, (100, 100), (6, 6), &settings(50_000), 3).unwrap();
+
+        assert_eq!(quote.rebate, 0);
+        assert_eq!(quote.amount_out, withdrawal.amount - quote.protocol_fee - quote.partner_fee);
+        assert_eq!(pool_b.epoch_rebates, 0);
+        assert_eq!(pool_b.protocol_income, 10_000 + quote.protocol_fee / 2);
+    }
+
+    #[test]
+    fn charging_withdrawal_fee() {
+        let mut pool_a = Pool {
+            withdrawal_fee: withdrawal_fee_params(),
+            ..pool(1_000_000, 800_000)
+        };
+        let mut pool_b = pool(1_000_000, 1_200_000);
+
+        let (withdrawal, quote) = withdraw_into(&mut pool_a, &mut pool_b, 100_000, (100, 100), (6, 6), &settings(50_000), 0).unwrap();
+
+        assert_eq!(withdrawal.fee, 1_760);
+        assert_eq!(withdrawal.amount, 80_000 - 1_760);
+        assert_eq!(quote.raw_amount_out, withdrawal.amount);
+
+        // The fee stays with pool A's remaining providers
+        assert_eq!(pool_a.cumulative_yield, 1_760 * SPICE_SCALE);
+        assert_eq!(pool_a.current_liquidity, 800_000 - 1_760);
+    }
+
+    #[test]
+    fn output_pool_liquidity_bound() {
+        let mut pool_a = pool(1_000_000, 1_000_000);
+        let mut pool_b = pool(1_000_000, 10_000);
+
+        assert!(withdraw_into(&mut pool_a, &mut pool_b, 100_000, (100, 100), (6, 6), &settings(50_000), 0).is_err());
+    }
+}
\ No newline at end of file
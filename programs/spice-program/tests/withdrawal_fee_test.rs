@@ -0,0 +1,73 @@
+mod common;
+
+use common::withdrawal_fee_params;
+use spice_program::{
+    constants::SPICE_SCALE,
+    functions::{quote_withdrawal, withdraw_with_fee, withdrawal_fee_rate},
+    states::Pool,
+};
+
+fn pool(initial_liquidity: u64, current_liquidity: u64) -> Pool {
+    Pool { withdrawal_fee: withdrawal_fee_params(), ..common::pool(initial_liquidity, current_liquidity) }
+}
+
+#[cfg(test)]
+mod withdrawal_fee {
+
+    use super::*;
+
+    #[test]
+    fn no_fee_at_target() {
+        assert_eq!(withdrawal_fee_rate(&pool(1_000_000, 1_000_000), 100_000).unwrap(), 0);
+        assert_eq!(withdrawal_fee_rate(&pool(1_000_000, 1_200_000), 100_000).unwrap(), 0);
+
+        let quote = quote_withdrawal(&pool(1_000_000, 1_000_000), 100_000).unwrap();
+        assert_eq!((quote.amount, quote.fee), (100_000, 0));
+    }
+
+    #[test]
+    fn fee_grows_with_deficit_depth() {
+        let shallow = withdrawal_fee_rate(&pool(1_000_000, 950_000), 10_000).unwrap();
+        let deep = withdrawal_fee_rate(&pool(1_000_000, 800_000), 10_000).unwrap();
+
+        assert!(shallow > 0);
+        assert!(deep > shallow);
+        assert_eq!(withdrawal_fee_rate(&pool(1_000_000, 100_000), 10_000).unwrap(), 5_000);
+    }
+
+    #[test]
+    fn fee_grows_with_withdrawal_size() {
+        let pool_a = pool(1_000_000, 900_000);
+
+        assert_eq!(withdrawal_fee_rate(&pool_a, 90_000).unwrap(), 1_100);
+        assert_eq!(withdrawal_fee_rate(&pool_a, 450_000).unwrap(), 1_500);
+    }
+
+    #[test]
+    fn quoting_withdrawal() {
+        let pool_a = pool(1_000_000, 900_000);
+
+        let quote = quote_withdrawal(&pool_a, 100_000).unwrap();
+        assert_eq!(quote.fee, 990);
+        assert_eq!(quote.amount, 90_000 - 990);
+
+        // The last provider out pays no fee
+        let quote = quote_withdrawal(&pool_a, 1_000_000).unwrap();
+        assert_eq!((quote.amount, quote.fee), (900_000, 0));
+
+        assert!(quote_withdrawal(&pool_a, 1_000_001).is_err());
+    }
+
+    #[test]
+    fn crediting_fee_to_remaining_providers() {
+        let mut pool_a = pool(1_000_000, 900_000);
+
+        let quote = withdraw_with_fee(&mut pool_a, 100_000).unwrap();
+
+        assert_eq!(quote.fee, 990);
+        assert_eq!(pool_a.cumulative_yield, 990 * SPICE_SCALE);
+        assert_eq!(pool_a.current_liquidity, 810_000);
+        assert_eq!(pool_a.initial_liquidity, 900_000);
+        assert_eq!(pool_a.lp_supply, 900_000);
+    }
+}